@@ -0,0 +1,27 @@
+//! Links `unit-ext` into a `#![no_std]` binary with no default
+//! features, so a stray `std` or `alloc` dependency fails to build.
+
+#![no_std]
+#![no_main]
+
+use core::hint::black_box;
+use core::panic::PanicInfo;
+use unit_ext::*;
+
+#[panic_handler]
+fn panic(_: &PanicInfo<'_>) -> ! {
+    loop {
+        core::hint::spin_loop();
+    }
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn _start() -> ! {
+    let port: Result<u16, ()> = ().ret_some(8080).ok_or_unit();
+    let digit = ().ret_char_from_digit(7, 10);
+    let sum: u32 = ().ret_repeat_n(2, 3).sum();
+    let _ = black_box((port, digit, sum, 5u8.into_some()));
+    loop {
+        core::hint::spin_loop();
+    }
+}
@@ -1,3 +1,7 @@
+// Passing a side-effecting unit expression as the receiver is the
+// whole point of the crate.
+#![allow(clippy::unit_arg)]
+
 use std::ops::Add;
 use unit_ext::*;
 
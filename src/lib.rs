@@ -1,5 +1,163 @@
 #![doc = include_str!("../README.md")]
 #![no_std]
+// Every `ret_*` helper is `#[must_use]`, including the ones returning
+// `Result`, so the API reads the same regardless of the return type.
+#![allow(clippy::double_must_use)]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
+
+#[cfg(feature = "alloc")]
+use alloc::{
+    borrow::{Cow, ToOwned},
+    boxed::Box,
+    collections::{BTreeMap, BTreeSet, VecDeque},
+    rc::Rc,
+    string::String,
+    vec::Vec,
+};
+use core::array;
+use core::cell::{Cell, RefCell};
+use core::cmp::Ordering;
+use core::fmt;
+use core::future;
+use core::iter::{
+    self, Chain, Empty, Enumerate, Once, Peekable, Repeat, RepeatN,
+    RepeatWith, Zip,
+};
+use core::marker::PhantomData;
+use core::mem::{ManuallyDrop, MaybeUninit};
+use core::num::{
+    NonZeroI8, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI128,
+    NonZeroIsize, NonZeroU8, NonZeroU16, NonZeroU32, NonZeroU64,
+    NonZeroU128, NonZeroUsize, Saturating, Wrapping,
+};
+use core::ops::{
+    ControlFlow, Deref, Range, RangeFrom, RangeFull, RangeInclusive,
+    RangeTo,
+};
+use core::pin::Pin;
+use core::slice;
+use core::str::{FromStr, Utf8Error};
+use core::sync::atomic;
+use core::task::{Context, Poll, Waker};
+use core::time::Duration;
+#[cfg(feature = "std")]
+use std::collections::{HashMap, HashSet};
+#[cfg(feature = "std")]
+use std::env;
+#[cfg(feature = "std")]
+use std::io;
+#[cfg(feature = "std")]
+use std::path::{Path, PathBuf};
+#[cfg(feature = "std")]
+use std::sync::{Arc, Mutex, RwLock};
+#[cfg(feature = "std")]
+use std::time::{Instant, SystemTime};
+
+/// Glob-importable re-exports: `use unit_ext::prelude::*;`.
+///
+/// Every public item at the crate root is part of the prelude, and it
+/// is re-exported with a glob so new extension traits are picked up
+/// without touching this module. Anything that should stay out of the
+/// prelude therefore has to live in its own module instead.
+///
+/// The example below calls one method from each trait, so it stops
+/// compiling if a trait ever goes missing here:
+///
+/// ```
+/// use unit_ext::prelude::*;
+///
+/// let _: Option<u8> = ().ret_some(1);
+/// let _: Option<u8> = 1.into_some();
+/// 1.into_unit();
+/// let _: u8 = 1.pipe(|n| n + 1);
+/// let _: u8 = 1.tap(|_| {});
+/// let _: Option<u8> = true.then_ret_some(1);
+/// let _: Result<u8, ()> = Some(1).ok_or_unit();
+/// let _: Option<u8> = Ok::<u8, ()>(1).ok_discard();
+/// let _: u8 = (1, 2).discard_first();
+/// let _: (u8, u8) = (1, 2, 3).discard_third();
+/// fn unit_like<U: UnitLike>(_: U) {}
+/// unit_like(());
+/// ```
+///
+/// `LogExt` joins them with the `log` feature:
+///
+#[cfg_attr(feature = "log", doc = "```")]
+#[cfg_attr(not(feature = "log"), doc = "```ignore")]
+/// use unit_ext::prelude::*;
+/// let _: u8 = 1.log_debug("n");
+/// ```
+pub mod prelude {
+    #[doc(no_inline)]
+    pub use crate::*;
+}
+
+/// Generates one `ret_non_zero_*` method per `NonZero*` integer type.
+macro_rules! ret_non_zero {
+    ($($name:ident => $nz:ident($int:ty),)*) => {$(
+        #[doc = concat!(
+            "Returns `", stringify!($nz), "::new(n)`, which is [`None`] ",
+            "when `n` is zero."
+        )]
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use unit_ext::*;
+        #[doc = concat!("assert_eq!(().", stringify!($name), "(0), None);")]
+        #[doc = concat!(
+            "assert_eq!(().", stringify!($name), "(7).map(|n| n.get()), ",
+            "Some(7));"
+        )]
+        /// ```
+        #[must_use]
+        #[inline]
+        fn $name(self, n: $int) -> Option<$nz> {
+            $nz::new(n)
+        }
+    )*};
+}
+
+/// Generates one `ret_atomic_*` method per atomic type, each gated on
+/// the target actually supporting atomics of that width.
+macro_rules! ret_atomic {
+    ($(
+        $width:literal: $name:ident => $atomic:ident($value:ty = $ex:literal),
+    )*) => {$(
+        #[doc = concat!("Returns `", stringify!($atomic), "::new(value)`.")]
+        ///
+        #[doc = concat!(
+            "Comes from `core`, so it needs no feature; it is only ",
+            "available on targets with `target_has_atomic = \"",
+            $width,
+            "\"`."
+        )]
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use unit_ext::*;
+        /// use std::sync::atomic::Ordering;
+        ///
+        #[doc = concat!(
+            "let a = ().", stringify!($name), "(", stringify!($ex), ");"
+        )]
+        #[doc = concat!(
+            "assert_eq!(a.load(Ordering::Relaxed), ", stringify!($ex), ");"
+        )]
+        /// ```
+        #[cfg(target_has_atomic = $width)]
+        #[must_use]
+        #[inline]
+        fn $name(self, value: $value) -> atomic::$atomic {
+            atomic::$atomic::new(value)
+        }
+    )*};
+}
 
 /// Extension methods for the unit type `()` that construct common wrapper
 /// values without explicit boiler-plate.
@@ -19,6 +177,56 @@
 ///
 /// let vec: Vec<u8> = ().ret_default();
 /// ```
+///
+/// The trait is implemented for every [`UnitLike`] type. Besides `()`,
+/// that includes [`Infallible`](core::convert::Infallible), so generic
+/// code can use the helpers on an error type that can never be
+/// constructed:
+///
+/// ```
+/// use unit_ext::*;
+/// use std::convert::Infallible;
+///
+/// fn wrap<U: UnitExt>(unit: U) -> Result<i32, String> {
+///     unit.ret_ok(1)
+/// }
+///
+/// fn recover(res: Result<i32, Infallible>) -> Result<i32, String> {
+///     match res {
+///         Ok(v) => Ok(v),
+///         Err(never) => wrap(never),
+///     }
+/// }
+///
+/// assert_eq!(wrap(()), Ok(1));
+/// assert_eq!(recover(Ok(2)), Ok(2));
+/// ```
+///
+/// # Feature flags
+///
+/// Helpers that allocate, such as `ret_box` or `ret_btree_map`, are
+/// only available with the `alloc` feature (enabled by the default
+/// `std` feature):
+///
+#[cfg_attr(feature = "alloc", doc = "```")]
+#[cfg_attr(not(feature = "alloc"), doc = "```compile_fail")]
+/// use unit_ext::*;
+/// use std::collections::BTreeMap;
+///
+/// let boxed = ().ret_box(1);
+/// let map: BTreeMap<u8, u8> = ().ret_btree_map();
+/// ```
+///
+/// Helpers for types that only exist in `std`, such as
+/// `ret_hash_map`, need the `std` feature itself:
+///
+#[cfg_attr(feature = "std", doc = "```")]
+#[cfg_attr(not(feature = "std"), doc = "```compile_fail")]
+/// use unit_ext::*;
+/// use std::collections::HashMap;
+///
+/// let map: HashMap<u8, u8> = ().ret_hash_map();
+/// ```
 pub trait UnitExt: Sized {
     /// Returns `value`.
     ///
@@ -39,6 +247,71 @@ pub trait UnitExt: Sized {
         value
     }
 
+    /// Calls `f` and returns its result.
+    ///
+    /// This is the lazy counterpart of [`ret`](UnitExt::ret), in the
+    /// same way `unwrap_or_else` relates to `unwrap_or`: the value is
+    /// only computed when the call is actually reached.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unit_ext::*;
+    /// use std::cell::Cell;
+    ///
+    /// let calls = Cell::new(0);
+    /// let costly = || {
+    ///     calls.set(calls.get() + 1);
+    ///     42
+    /// };
+    ///
+    /// let v = Some(1).unwrap_or_else(|| {
+    ///     println!("fallback").ret_with(costly)
+    /// });
+    /// assert_eq!(v, 1);
+    /// assert_eq!(calls.get(), 0);
+    ///
+    /// let v = None.unwrap_or_else(|| {
+    ///     println!("fallback").ret_with(costly)
+    /// });
+    /// assert_eq!(v, 42);
+    /// assert_eq!(calls.get(), 1);
+    /// ```
+    #[must_use]
+    #[inline]
+    fn ret_with<T, F: FnOnce() -> T>(self, f: F) -> T {
+        f()
+    }
+
+    /// Returns a closure that yields `value` when called.
+    ///
+    /// The value is moved into the closure, which can only be called
+    /// once:
+    ///
+    /// ```compile_fail
+    /// use unit_ext::*;
+    /// let thunk = ().ret_fn_once(String::from("data"));
+    /// let first = thunk();
+    /// let second = thunk();
+    /// ```
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unit_ext::*;
+    ///
+    /// let mut callbacks: Vec<Box<dyn FnOnce() -> String>> = Vec::new();
+    /// callbacks.push(Box::new(println!("prepared").ret_fn_once(String::from("data"))));
+    ///
+    /// let results: Vec<String> = callbacks.into_iter().map(|f| f()).collect();
+    /// assert_eq!(results, ["data"]);
+    /// ```
+    #[must_use]
+    #[inline]
+    fn ret_fn_once<T>(self, value: T) -> impl FnOnce() -> T {
+        move || value
+    }
+
     /// Returns `T::default()`.
     ///
     /// # Examples
@@ -54,6 +327,74 @@ pub trait UnitExt: Sized {
         T::default()
     }
 
+    /// Returns `if_true` if `condition` holds, otherwise `if_false`.
+    ///
+    /// Both values are evaluated up front; see
+    /// [`ret_if_then_else_lazy`](UnitExt::ret_if_then_else_lazy) to
+    /// build only the one that is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unit_ext::*;
+    ///
+    /// let verbose = true;
+    /// let level = println!("reading flags").ret_if_then_else(verbose, "debug", "info");
+    /// assert_eq!(level, "debug");
+    /// assert_eq!(().ret_if_then_else(false, 1, 2), 2);
+    /// ```
+    #[must_use]
+    #[inline]
+    fn ret_if_then_else<T>(
+        self,
+        condition: bool,
+        if_true: T,
+        if_false: T,
+    ) -> T {
+        if condition { if_true } else { if_false }
+    }
+
+    /// Returns `if_true()` if `condition` holds, otherwise
+    /// `if_false()`.
+    ///
+    /// Only the closure for the taken branch is called.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unit_ext::*;
+    /// use std::cell::Cell;
+    ///
+    /// let (prod, test) = (Cell::new(0), Cell::new(0));
+    /// let config = |production: bool| {
+    ///     ().ret_if_then_else_lazy(
+    ///         production,
+    ///         || prod.set(prod.get() + 1).ret("production"),
+    ///         || test.set(test.get() + 1).ret("test"),
+    ///     )
+    /// };
+    ///
+    /// assert_eq!(config(true), "production");
+    /// assert_eq!((prod.get(), test.get()), (1, 0));
+    ///
+    /// assert_eq!(config(false), "test");
+    /// assert_eq!((prod.get(), test.get()), (1, 1));
+    /// ```
+    #[must_use]
+    #[inline]
+    fn ret_if_then_else_lazy<T, F, G>(
+        self,
+        condition: bool,
+        if_true: F,
+        if_false: G,
+    ) -> T
+    where
+        F: FnOnce() -> T,
+        G: FnOnce() -> T,
+    {
+        if condition { if_true() } else { if_false() }
+    }
+
     /// Returns [`None`].
     ///
     /// # Examples
@@ -87,6 +428,32 @@ pub trait UnitExt: Sized {
         value.into()
     }
 
+    /// Calls `f` and wraps its result in [`Some`].
+    ///
+    /// The lazy counterpart of [`ret_some`](UnitExt::ret_some); `f`
+    /// is only called when the method itself is reached.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unit_ext::*;
+    ///
+    /// let opt = Some(1).or_else(|| {
+    ///     ().ret_some_with(|| panic!("never called"))
+    /// });
+    /// assert_eq!(opt, Some(1));
+    ///
+    /// let opt = None.or_else(|| {
+    ///     println!("fallback").ret_some_with(|| 2)
+    /// });
+    /// assert_eq!(opt, Some(2));
+    /// ```
+    #[must_use]
+    #[inline]
+    fn ret_some_with<T, F: FnOnce() -> T>(self, f: F) -> Option<T> {
+        Some(f())
+    }
+
     /// Returns `Some(T::default())`.
     ///
     /// # Examples
@@ -102,6 +469,251 @@ pub trait UnitExt: Sized {
         self.ret_default::<T>().into()
     }
 
+    /// Returns `Some(value)` if `condition` holds, otherwise [`None`].
+    ///
+    /// `value` is always evaluated and, when `condition` is `false`,
+    /// dropped. Use [`ret_some_if_with`](UnitExt::ret_some_if_with) to
+    /// construct it lazily.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unit_ext::*;
+    ///
+    /// assert_eq!(().ret_some_if(true, 5), Some(5));
+    /// assert_eq!(().ret_some_if(false, 5), None);
+    /// ```
+    #[must_use]
+    #[inline]
+    fn ret_some_if<T>(self, condition: bool, value: T) -> Option<T> {
+        if condition { Some(value) } else { None }
+    }
+
+    /// Returns `Some(f())` if `condition` holds, otherwise [`None`].
+    ///
+    /// `f` is only called when `condition` is `true`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unit_ext::*;
+    /// assert_eq!(().ret_some_if_with(true, || 1), Some(1));
+    /// assert_eq!(
+    ///     ().ret_some_if_with(false, || -> u8 { panic!() }),
+    ///     None
+    /// );
+    /// ```
+    #[must_use]
+    #[inline]
+    fn ret_some_if_with<T, F: FnOnce() -> T>(
+        self,
+        condition: bool,
+        f: F,
+    ) -> Option<T> {
+        condition.then(f)
+    }
+
+    /// Returns [`None`] if `condition` holds, otherwise `Some(value)`.
+    ///
+    /// The inverse of [`ret_some_if`](UnitExt::ret_some_if).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unit_ext::*;
+    ///
+    /// assert_eq!(().ret_none_if(true, 5), None);
+    /// assert_eq!(().ret_none_if(false, 5), Some(5));
+    /// ```
+    #[must_use]
+    #[inline]
+    fn ret_none_if<T>(self, condition: bool, value: T) -> Option<T> {
+        self.ret_some_if(!condition, value)
+    }
+
+    /// Returns [`None`] if `condition` holds, otherwise `Some(f())`.
+    ///
+    /// `f` is only called when `condition` is `false`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unit_ext::*;
+    /// assert_eq!(().ret_none_if_with(false, || 1), Some(1));
+    /// assert_eq!(
+    ///     ().ret_none_if_with(true, || -> u8 { panic!() }),
+    ///     None
+    /// );
+    /// ```
+    #[must_use]
+    #[inline]
+    fn ret_none_if_with<T, F: FnOnce() -> T>(
+        self,
+        condition: bool,
+        f: F,
+    ) -> Option<T> {
+        self.ret_some_if_with(!condition, f)
+    }
+
+    /// Returns `f()`, an `Option` built by a closure.
+    ///
+    /// Keeps a multi-line construction block in expression position
+    /// without a separate `let` or helper function.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unit_ext::*;
+    ///
+    /// let input = "key=value";
+    /// let pair = println!("splitting").ret_option_from_fn(|| {
+    ///     let (key, value) = input.split_once('=')?;
+    ///     (!key.is_empty()).then_some((key, value))
+    /// });
+    /// assert_eq!(pair, Some(("key", "value")));
+    /// ```
+    #[must_use]
+    #[inline]
+    fn ret_option_from_fn<T, F: FnOnce() -> Option<T>>(
+        self,
+        f: F,
+    ) -> Option<T> {
+        f()
+    }
+
+    /// Returns `Some(T::default())` if `condition` holds, otherwise
+    /// [`None`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unit_ext::*;
+    ///
+    /// #[derive(Debug, Default, PartialEq)]
+    /// struct Metrics {
+    ///     hits: u32,
+    /// }
+    ///
+    /// let enabled = println!("checking flag").ret_default_if::<Metrics>(true);
+    /// assert_eq!(enabled, Some(Metrics { hits: 0 }));
+    ///
+    /// let disabled: Option<Metrics> = ().ret_default_if(false);
+    /// assert_eq!(disabled, None);
+    /// ```
+    #[must_use]
+    #[inline]
+    fn ret_default_if<T: Default>(
+        self,
+        condition: bool,
+    ) -> Option<T> {
+        self.ret_some_if_with(condition, T::default)
+    }
+
+    /// Returns `Some(T::default())` unless `condition` holds, in which
+    /// case it returns [`None`].
+    ///
+    /// The inverse of [`ret_default_if`](UnitExt::ret_default_if).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unit_ext::*;
+    ///
+    /// fn buffer(quiet: bool) -> Option<Vec<u8>> {
+    ///     ().ret_default_unless(quiet)
+    /// }
+    ///
+    /// assert_eq!(buffer(false), Some(vec![]));
+    /// assert_eq!(buffer(true), None);
+    /// ```
+    #[must_use]
+    #[inline]
+    fn ret_default_unless<T: Default>(
+        self,
+        condition: bool,
+    ) -> Option<T> {
+        self.ret_default_if(!condition)
+    }
+
+    /// Returns `f(value)`, the same as `().ret_some(value).and_then(f)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unit_ext::*;
+    ///
+    /// let half = |n: u32| (n % 2 == 0).then_some(n / 2);
+    /// assert_eq!(println!("halving").ret_some_chain(8, half), Some(4));
+    /// assert_eq!(().ret_some_chain(7, half), None);
+    /// assert_eq!(().ret_some_chain(8, half), ().ret_some(8).and_then(half));
+    /// ```
+    #[must_use]
+    #[inline]
+    fn ret_some_chain<T, U, F: FnOnce(T) -> Option<U>>(
+        self,
+        value: T,
+        f: F,
+    ) -> Option<U> {
+        f(value)
+    }
+
+    /// Returns [`option.map_or(default, f)`](Option::map_or).
+    ///
+    /// `default` is evaluated eagerly, as with `map_or`; `f` only runs
+    /// on `Some`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unit_ext::*;
+    /// use std::{cell::Cell, collections::HashMap};
+    ///
+    /// let cache = HashMap::from([("a", 3)]);
+    /// let calls = Cell::new(0);
+    /// let lookup = |key| {
+    ///     println!("lookup").ret_map_or(cache.get(key), 0, |v| {
+    ///         calls.set(calls.get() + 1);
+    ///         *v * 2
+    ///     })
+    /// };
+    ///
+    /// assert_eq!(lookup("a"), 6);
+    /// assert_eq!(lookup("b"), 0);
+    /// assert_eq!(calls.get(), 1);
+    /// ```
+    #[must_use]
+    #[inline]
+    fn ret_map_or<T, U, F: FnOnce(T) -> U>(
+        self,
+        option: Option<T>,
+        default: U,
+        f: F,
+    ) -> U {
+        option.map_or(default, f)
+    }
+
+    /// Returns [`option.and_then(f)`](Option::and_then).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unit_ext::*;
+    ///
+    /// let parse = |s: &str| s.parse::<u8>().ok();
+    /// assert_eq!(().ret_and_then_option(Some("7"), parse), Some(7));
+    /// assert_eq!(().ret_and_then_option(Some("x"), parse), None);
+    /// assert_eq!(().ret_and_then_option(None, parse), None);
+    /// ```
+    #[must_use]
+    #[inline]
+    fn ret_and_then_option<T, U, F: FnOnce(T) -> Option<U>>(
+        self,
+        option: Option<T>,
+        f: F,
+    ) -> Option<U> {
+        option.and_then(f)
+    }
+
     /// Returns `Err(value)`.
     ///
     /// # Examples
@@ -117,6 +729,43 @@ pub trait UnitExt: Sized {
         Err(value)
     }
 
+    /// Calls `f` and returns its result wrapped in [`Err`].
+    ///
+    /// The lazy counterpart of [`ret_err`](UnitExt::ret_err); the
+    /// error is only constructed when the error branch is reached.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unit_ext::*;
+    /// use std::cell::Cell;
+    ///
+    /// let built = Cell::new(0);
+    /// let make_err = || {
+    ///     built.set(built.get() + 1);
+    ///     "bad input"
+    /// };
+    ///
+    /// let parse = |s: &str| {
+    ///     s.parse::<u8>()
+    ///         .or_else(|e| eprintln!("{e}").ret_err_with(make_err))
+    /// };
+    ///
+    /// assert_eq!(parse("7"), Ok(7));
+    /// assert_eq!(built.get(), 0);
+    ///
+    /// assert_eq!(parse("x"), Err("bad input"));
+    /// assert_eq!(built.get(), 1);
+    /// ```
+    #[must_use]
+    #[inline]
+    fn ret_err_with<T, E, F: FnOnce() -> E>(
+        self,
+        f: F,
+    ) -> Result<T, E> {
+        Err(f())
+    }
+
     /// Returns `Err(E::default())`.
     ///
     /// # Examples
@@ -147,6 +796,42 @@ pub trait UnitExt: Sized {
         Ok(value)
     }
 
+    /// Calls `f` and returns its result wrapped in [`Ok`].
+    ///
+    /// The lazy counterpart of [`ret_ok`](UnitExt::ret_ok).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unit_ext::*;
+    /// use std::cell::Cell;
+    ///
+    /// let built = Cell::new(0);
+    /// let make_ok = || {
+    ///     built.set(built.get() + 1);
+    ///     0
+    /// };
+    ///
+    /// let r: Result<u8, &str> = Ok(3);
+    /// let r: Result<_, ()> = r.or_else(|_| ().ret_ok_with(make_ok));
+    /// assert_eq!(r, Ok(3));
+    /// assert_eq!(built.get(), 0);
+    ///
+    /// let r: Result<u8, &str> = Err("boom");
+    /// let r: Result<_, ()> =
+    ///     r.or_else(|e| eprintln!("{e}").ret_ok_with(make_ok));
+    /// assert_eq!(r, Ok(0));
+    /// assert_eq!(built.get(), 1);
+    /// ```
+    #[must_use]
+    #[inline]
+    fn ret_ok_with<T, E, F: FnOnce() -> T>(
+        self,
+        f: F,
+    ) -> Result<T, E> {
+        Ok(f())
+    }
+
     /// Returns `Ok(T::default())`.
     ///
     /// # Examples
@@ -161,37 +846,5387 @@ pub trait UnitExt: Sized {
     fn ret_ok_default<T: Default, E>(self) -> Result<T, E> {
         self.ret_ok(T::default())
     }
-}
 
-/// Extension methods for any value that explicitly discard the value
-/// and yield unit `()`.
+    /// Returns `Ok(())` typed as [`fmt::Result`].
+    ///
+    /// The usual last expression of a `Display` or `Debug` impl.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unit_ext::*;
+    /// use std::fmt;
+    ///
+    /// struct Point(i32, i32);
+    ///
+    /// impl fmt::Display for Point {
+    ///     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    ///         if self.0 == 0 && self.1 == 0 {
+    ///             return f.write_str("origin").and(().ret_fmt_ok());
+    ///         }
+    ///         write!(f, "({}, {})", self.0, self.1)?;
+    ///         ().ret_fmt_ok()
+    ///     }
+    /// }
+    ///
+    /// assert_eq!(Point(1, 2).to_string(), "(1, 2)");
+    /// assert_eq!(Point(0, 0).to_string(), "origin");
+    /// ```
+    #[must_use]
+    #[inline]
+    fn ret_fmt_ok(self) -> fmt::Result {
+        Ok(())
+    }
+
+    /// Returns [`writer.write_str(s)`](fmt::Write::write_str).
+    ///
+    /// # Errors
+    ///
+    /// Propagates any error reported by `writer`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unit_ext::*;
+    ///
+    /// let mut out = String::new();
+    /// ().ret_write_str(&mut out, "prefix")
+    ///     .and_then(|()| ().ret_write_char(&mut out, ':'))
+    ///     .and_then(|()| ().ret_write_str(&mut out, " value"))
+    ///     .unwrap();
+    /// assert_eq!(out, "prefix: value");
+    /// ```
+    #[must_use]
+    #[inline]
+    fn ret_write_str<W: fmt::Write + ?Sized>(
+        self,
+        writer: &mut W,
+        s: &str,
+    ) -> fmt::Result {
+        writer.write_str(s)
+    }
+
+    /// Returns [`writer.write_char(c)`](fmt::Write::write_char).
+    ///
+    /// # Errors
+    ///
+    /// Propagates any error reported by `writer`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unit_ext::*;
+    /// use std::fmt;
+    ///
+    /// struct Tag(&'static str);
+    ///
+    /// impl fmt::Display for Tag {
+    ///     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    ///         ().ret_write_char(f, '<')?;
+    ///         ().ret_write_str(f, self.0)?;
+    ///         ().ret_write_char(f, '>')
+    ///     }
+    /// }
+    ///
+    /// assert_eq!(Tag("b").to_string(), "<b>");
+    /// ```
+    #[must_use]
+    #[inline]
+    fn ret_write_char<W: fmt::Write + ?Sized>(
+        self,
+        writer: &mut W,
+        c: char,
+    ) -> fmt::Result {
+        writer.write_char(c)
+    }
+
+    /// Returns `Ok(value)` if `condition` holds, otherwise `Err(error)`.
+    ///
+    /// Both `value` and `error` are evaluated up front and the one
+    /// that is not returned is dropped. Use
+    /// [`ret_ok_if_with`](UnitExt::ret_ok_if_with) to construct only
+    /// the taken branch.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unit_ext::*;
+    ///
+    /// let name = String::from("ferris");
+    /// let res = ().ret_ok_if(!name.is_empty(), name, "empty name");
+    /// assert_eq!(res.as_deref(), Ok("ferris"));
+    ///
+    /// let res = ().ret_ok_if(false, String::new(), "empty name");
+    /// assert_eq!(res, Err("empty name"));
+    /// ```
+    #[must_use]
+    #[inline]
+    fn ret_ok_if<T, E>(
+        self,
+        condition: bool,
+        value: T,
+        error: E,
+    ) -> Result<T, E> {
+        if condition { Ok(value) } else { Err(error) }
+    }
+
+    /// Returns `Ok(f())` if `condition` holds, otherwise `Err(g())`.
+    ///
+    /// Only the closure for the taken branch is called.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unit_ext::*;
+    ///
+    /// let res: Result<u8, &str> =
+    ///     ().ret_ok_if_with(true, || 1, || panic!("not taken"));
+    /// assert_eq!(res, Ok(1));
+    ///
+    /// let res: Result<u8, &str> =
+    ///     ().ret_ok_if_with(false, || panic!("not taken"), || "no");
+    /// assert_eq!(res, Err("no"));
+    /// ```
+    #[must_use]
+    #[inline]
+    fn ret_ok_if_with<T, E, F, G>(
+        self,
+        condition: bool,
+        f: F,
+        g: G,
+    ) -> Result<T, E>
+    where
+        F: FnOnce() -> T,
+        G: FnOnce() -> E,
+    {
+        if condition { Ok(f()) } else { Err(g()) }
+    }
+
+    /// Returns `Err(error)` if `condition` holds, otherwise `Ok(value)`.
+    ///
+    /// The inverse of [`ret_ok_if`](UnitExt::ret_ok_if), for conditions
+    /// that describe the failure case.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unit_ext::*;
+    ///
+    /// let input = "";
+    /// let res = ().ret_err_if(input.is_empty(), input, "empty input");
+    /// assert_eq!(res, Err("empty input"));
+    ///
+    /// let res = ().ret_err_if(false, "abc", "empty input");
+    /// assert_eq!(res, Ok("abc"));
+    /// ```
+    #[must_use]
+    #[inline]
+    fn ret_err_if<T, E>(
+        self,
+        condition: bool,
+        value: T,
+        error: E,
+    ) -> Result<T, E> {
+        self.ret_ok_if(!condition, value, error)
+    }
+
+    /// Returns `Err(g())` if `condition` holds, otherwise `Ok(f())`.
+    ///
+    /// Only the closure for the taken branch is called.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unit_ext::*;
+    /// use std::cell::Cell;
+    ///
+    /// let (oks, errs) = (Cell::new(0), Cell::new(0));
+    /// let check = |failed: bool| -> Result<(), ()> {
+    ///     ().ret_err_if_with(
+    ///         failed,
+    ///         || oks.set(oks.get() + 1),
+    ///         || errs.set(errs.get() + 1),
+    ///     )
+    /// };
+    ///
+    /// assert!(check(true).is_err());
+    /// assert_eq!((oks.get(), errs.get()), (0, 1));
+    ///
+    /// assert!(check(false).is_ok());
+    /// assert_eq!((oks.get(), errs.get()), (1, 1));
+    /// ```
+    #[must_use]
+    #[inline]
+    fn ret_err_if_with<T, E, F, G>(
+        self,
+        condition: bool,
+        f: F,
+        g: G,
+    ) -> Result<T, E>
+    where
+        F: FnOnce() -> T,
+        G: FnOnce() -> E,
+    {
+        self.ret_ok_if_with(!condition, f, g)
+    }
+
+    /// Returns `Ok(Some(value))`.
+    ///
+    /// `Result<Option<T>, E>` is the usual shape of a fallible lookup:
+    /// the query may fail, and when it succeeds it may find nothing.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unit_ext::*;
+    ///
+    /// fn find(id: u32) -> Result<Option<&'static str>, String> {
+    ///     match id {
+    ///         0 => ().ret_err(String::from("invalid id")),
+    ///         1 => println!("hit").ret_ok_some("ferris"),
+    ///         _ => println!("miss").ret_ok_none(),
+    ///     }
+    /// }
+    ///
+    /// assert_eq!(find(1), Ok(Some("ferris")));
+    /// assert_eq!(find(2), Ok(None));
+    /// assert!(find(0).is_err());
+    /// ```
+    #[must_use]
+    #[inline]
+    fn ret_ok_some<T, E>(self, value: T) -> Result<Option<T>, E> {
+        Ok(Some(value))
+    }
+
+    /// Returns `Ok(None)`.
+    ///
+    /// See [`ret_ok_some`](UnitExt::ret_ok_some) for an example.
+    #[must_use]
+    #[inline]
+    fn ret_ok_none<T, E>(self) -> Result<Option<T>, E> {
+        Ok(None)
+    }
+
+    /// Returns `Err(Some(err))`.
+    ///
+    /// `Result<T, Option<E>>` fits operations whose failure may or may
+    /// not carry a reason.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unit_ext::*;
+    ///
+    /// fn parse(s: &str) -> Result<u8, Option<&'static str>> {
+    ///     if s.is_empty() {
+    ///         return println!("nothing to parse").ret_err_none();
+    ///     }
+    ///     s.parse().or_else(|_| ().ret_err_some("not a u8"))
+    /// }
+    ///
+    /// assert_eq!(parse("7"), Ok(7));
+    /// assert_eq!(parse(""), Err(None));
+    /// assert_eq!(parse("x"), Err(Some("not a u8")));
+    /// ```
+    #[must_use]
+    #[inline]
+    fn ret_err_some<T, E>(self, err: E) -> Result<T, Option<E>> {
+        Err(Some(err))
+    }
+
+    /// Returns `Err(None)`.
+    ///
+    /// See [`ret_err_some`](UnitExt::ret_err_some) for an example.
+    #[must_use]
+    #[inline]
+    fn ret_err_none<T, E>(self) -> Result<T, Option<E>> {
+        Err(None)
+    }
+
+    /// Returns `Some(Ok(value))`.
+    ///
+    /// `Option<Result<T, E>>` is the shape of a stream item: `None`
+    /// ends the stream and `Some(Err(e))` reports a failed item.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unit_ext::*;
+    ///
+    /// fn next(queue: &mut Vec<&str>) -> Option<Result<u8, String>> {
+    ///     let raw = queue.pop()?;
+    ///     match raw.parse() {
+    ///         Ok(n) => ().ret_some_ok(n),
+    ///         Err(_) => ().ret_some_err(format!("bad item {raw:?}")),
+    ///     }
+    /// }
+    ///
+    /// let mut queue = vec!["x", "1"];
+    /// assert_eq!(next(&mut queue), Some(Ok(1)));
+    /// assert!(matches!(next(&mut queue), Some(Err(_))));
+    /// assert_eq!(next(&mut queue), None);
+    ///
+    /// let item = ().ret_some_ok::<_, ()>(5);
+    /// assert_eq!(item.transpose(), Ok(Some(5)));
+    /// ```
+    #[must_use]
+    #[inline]
+    fn ret_some_ok<T, E>(self, value: T) -> Option<Result<T, E>> {
+        Some(Ok(value))
+    }
+
+    /// Returns `Some(Err(err))`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unit_ext::*;
+    ///
+    /// let item = ().ret_some_err::<u8, _>("io");
+    /// assert_eq!(item.transpose(), Err("io"));
+    /// ```
+    #[must_use]
+    #[inline]
+    fn ret_some_err<T, E>(self, err: E) -> Option<Result<T, E>> {
+        Some(Err(err))
+    }
+
+    /// Returns `f()`, a `Result` built by a closure.
+    ///
+    /// Lets `?` be used inside a block that stays in expression
+    /// position.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unit_ext::*;
+    /// use std::num::ParseIntError;
+    ///
+    /// let area = println!("parsing size").ret_result_from_fn(|| {
+    ///     let (w, h) = ("3", "4");
+    ///     Ok::<_, ParseIntError>(w.parse::<u32>()? * h.parse::<u32>()?)
+    /// });
+    /// assert_eq!(area, Ok(12));
+    /// ```
+    #[must_use]
+    #[inline]
+    fn ret_result_from_fn<T, E, F: FnOnce() -> Result<T, E>>(
+        self,
+        f: F,
+    ) -> Result<T, E> {
+        f()
+    }
+
+    /// Returns `Ok(value)` if `opt` is `Some(value)`, otherwise
+    /// `Err(err)`.
+    ///
+    /// Same as [`Option::ok_or`], with the direction of the conversion
+    /// in the name.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unit_ext::*;
+    /// use std::collections::HashMap;
+    ///
+    /// #[derive(Debug, PartialEq)]
+    /// struct CacheMiss;
+    ///
+    /// let cache = HashMap::from([("a", 1)]);
+    /// let lookup = |key| println!("lookup").ret_ok_if_some(cache.get(key), CacheMiss);
+    /// assert_eq!(lookup("a"), Ok(&1));
+    /// assert_eq!(lookup("b"), Err(CacheMiss));
+    /// ```
+    #[must_use]
+    #[inline]
+    fn ret_ok_if_some<T, E>(
+        self,
+        opt: Option<T>,
+        err: E,
+    ) -> Result<T, E> {
+        opt.ok_or(err)
+    }
+
+    /// Returns `Ok(value)` if `opt` is `Some(value)`, otherwise
+    /// `Err(f())`.
+    ///
+    /// The lazy form of [`ret_ok_if_some`](UnitExt::ret_ok_if_some);
+    /// `f` only runs on `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unit_ext::*;
+    /// use std::cell::Cell;
+    ///
+    /// let built = Cell::new(0);
+    /// let err = || built.set(built.get() + 1).ret("missing");
+    ///
+    /// assert_eq!(().ret_ok_if_some_with(Some(1), err), Ok(1));
+    /// assert_eq!(built.get(), 0);
+    ///
+    /// assert_eq!(().ret_ok_if_some_with(None::<u8>, err), Err("missing"));
+    /// assert_eq!(built.get(), 1);
+    /// ```
+    #[must_use]
+    #[inline]
+    fn ret_ok_if_some_with<T, E, F: FnOnce() -> E>(
+        self,
+        opt: Option<T>,
+        f: F,
+    ) -> Result<T, E> {
+        opt.ok_or_else(f)
+    }
+
+    /// Returns `Some(value)` if `result` is `Ok(value)`, otherwise
+    /// [`None`].
+    ///
+    /// Same as [`Result::ok`], with the direction of the conversion in
+    /// the name.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unit_ext::*;
+    ///
+    /// assert_eq!(().ret_some_if_ok("7".parse::<u8>()), Some(7));
+    /// assert_eq!(().ret_some_if_ok("x".parse::<u8>()), None);
+    /// ```
+    #[must_use]
+    #[inline]
+    fn ret_some_if_ok<T, E>(self, result: Result<T, E>) -> Option<T> {
+        result.ok()
+    }
+
+    /// Returns `f(value)`, the same as `().ret_ok(value).and_then(f)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unit_ext::*;
+    /// use std::num::ParseIntError;
+    ///
+    /// fn parse(raw: &str) -> Result<u8, ParseIntError> {
+    ///     raw.trim().parse()
+    /// }
+    ///
+    /// let ok = println!("validated").ret_ok_chain(" 42 ", parse);
+    /// assert_eq!(ok, Ok(42));
+    ///
+    /// let failed = ().ret_ok_chain("x", parse);
+    /// assert_eq!(failed, ().ret_ok("x").and_then(parse));
+    /// assert!(failed.is_err());
+    /// ```
+    #[must_use]
+    #[inline]
+    fn ret_ok_chain<T, E, U, F: FnOnce(T) -> Result<U, E>>(
+        self,
+        value: T,
+        f: F,
+    ) -> Result<U, E> {
+        f(value)
+    }
+
+    /// Returns `Poll::Ready(value)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unit_ext::*;
+    /// use std::future::Future;
+    /// use std::pin::Pin;
+    /// use std::task::{Context, Poll, Waker};
+    ///
+    /// struct YieldOnce(bool);
+    ///
+    /// impl Future for YieldOnce {
+    ///     type Output = u8;
+    ///
+    ///     fn poll(
+    ///         mut self: Pin<&mut Self>,
+    ///         cx: &mut Context<'_>,
+    ///     ) -> Poll<u8> {
+    ///         if self.0 {
+    ///             ().ret_poll_ready(7)
+    ///         } else {
+    ///             self.0 = true;
+    ///             cx.waker().wake_by_ref().ret_poll_pending()
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// let mut cx = Context::from_waker(Waker::noop());
+    /// let mut fut = YieldOnce(false);
+    /// let mut fut = Pin::new(&mut fut);
+    ///
+    /// assert_eq!(fut.as_mut().poll(&mut cx), Poll::Pending);
+    /// assert_eq!(fut.as_mut().poll(&mut cx), Poll::Ready(7));
+    /// ```
+    #[must_use]
+    #[inline]
+    fn ret_poll_ready<T>(self, value: T) -> Poll<T> {
+        Poll::Ready(value)
+    }
+
+    /// Returns `Poll::Pending`.
+    ///
+    /// Typically used right after registering the waker in a
+    /// hand-written [`Future::poll`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unit_ext::*;
+    /// use std::task::{Poll, Waker};
+    ///
+    /// let p = Waker::noop().wake_by_ref().ret_poll_pending::<u8>();
+    /// assert!(p.is_pending());
+    /// ```
+    #[must_use]
+    #[inline]
+    fn ret_poll_pending<T>(self) -> Poll<T> {
+        Poll::Pending
+    }
+
+    /// Returns [`future::ready(value)`](future::ready), a future that
+    /// resolves to `value` on its first poll.
+    ///
+    /// The `Future` level counterpart of
+    /// [`ret_poll_ready`](UnitExt::ret_poll_ready).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unit_ext::*;
+    /// use std::{
+    ///     future::Future,
+    ///     pin::pin,
+    ///     task::{Context, Poll, Waker},
+    /// };
+    ///
+    /// let mut cx = Context::from_waker(Waker::noop());
+    /// let mut fut = pin!(println!("cached").ret_future_ready(7));
+    /// assert_eq!(fut.as_mut().poll(&mut cx), Poll::Ready(7));
+    /// ```
+    #[must_use]
+    #[inline]
+    fn ret_future_ready<T>(self, value: T) -> future::Ready<T> {
+        future::ready(value)
+    }
+
+    /// Returns [`future::pending()`](future::pending), a future that
+    /// never resolves.
+    ///
+    /// The `Future` level counterpart of
+    /// [`ret_poll_pending`](UnitExt::ret_poll_pending).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unit_ext::*;
+    /// use std::{
+    ///     future::Future,
+    ///     pin::pin,
+    ///     task::{Context, Poll, Waker},
+    /// };
+    ///
+    /// let mut cx = Context::from_waker(Waker::noop());
+    /// let mut fut = pin!(().ret_future_pending::<u8>());
+    /// for _ in 0..3 {
+    ///     assert_eq!(fut.as_mut().poll(&mut cx), Poll::Pending);
+    /// }
+    /// ```
+    #[must_use]
+    #[inline]
+    fn ret_future_pending<T>(self) -> future::Pending<T> {
+        future::pending()
+    }
+
+    /// Returns a [`Waker`] that does nothing when woken.
+    ///
+    /// Built on [`Waker::noop`] (Rust 1.85, the crate's MSRV), so no
+    /// `unsafe` vtable is needed and the helper works without `std`.
+    /// Mostly useful for polling hand-written futures in tests.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unit_ext::*;
+    ///
+    /// let waker = ().ret_noop_waker();
+    /// waker.wake_by_ref();
+    /// assert!(waker.will_wake(&waker.clone()));
+    /// ```
+    #[must_use]
+    #[inline]
+    fn ret_noop_waker(self) -> Waker {
+        Waker::noop().clone()
+    }
+
+    /// Returns [`Context::from_waker(waker)`](Context::from_waker).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unit_ext::*;
+    /// use std::{future::Future, pin::pin, task::Poll};
+    ///
+    /// let waker = ().ret_noop_waker();
+    /// let mut cx = ().ret_context(&waker);
+    ///
+    /// let mut ready = pin!(().ret_future_ready("done"));
+    /// assert_eq!(ready.as_mut().poll(&mut cx), Poll::Ready("done"));
+    /// ```
+    #[must_use]
+    #[inline]
+    fn ret_context(self, waker: &Waker) -> Context<'_> {
+        Context::from_waker(waker)
+    }
+
+    /// Returns `ControlFlow::Break(value)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unit_ext::*;
+    /// use std::ops::ControlFlow;
+    ///
+    /// fn first_negative(xs: &[i32]) -> ControlFlow<i32> {
+    ///     xs.iter().try_for_each(|&x| {
+    ///         if x < 0 {
+    ///             println!("found {x}").ret_control_flow_break(x)
+    ///         } else {
+    ///             ().ret_control_flow_continue(())
+    ///         }
+    ///     })
+    /// }
+    ///
+    /// assert_eq!(first_negative(&[1, -2, -3]), ControlFlow::Break(-2));
+    /// assert_eq!(first_negative(&[1, 2]), ControlFlow::Continue(()));
+    /// ```
+    #[must_use]
+    #[inline]
+    fn ret_control_flow_break<B, C>(
+        self,
+        value: B,
+    ) -> ControlFlow<B, C> {
+        ControlFlow::Break(value)
+    }
+
+    /// Returns `ControlFlow::Continue(value)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unit_ext::*;
+    /// use std::ops::ControlFlow;
+    ///
+    /// struct Visitor {
+    ///     seen: usize,
+    /// }
+    ///
+    /// impl Visitor {
+    ///     fn visit(&mut self, node: &str) -> ControlFlow<()> {
+    ///         self.seen += 1;
+    ///         if node == "stop" {
+    ///             ().ret_control_flow_break(())
+    ///         } else {
+    ///             println!("visiting {node}").ret_control_flow_continue(())
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// let mut v = Visitor { seen: 0 };
+    /// let flow = ["a", "b", "stop", "c"]
+    ///     .into_iter()
+    ///     .try_for_each(|n| v.visit(n));
+    ///
+    /// assert!(flow.is_break());
+    /// assert_eq!(v.seen, 3);
+    /// ```
+    #[must_use]
+    #[inline]
+    fn ret_control_flow_continue<B, C>(
+        self,
+        value: C,
+    ) -> ControlFlow<B, C> {
+        ControlFlow::Continue(value)
+    }
+
+    /// Returns `Cell::new(value)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unit_ext::*;
+    ///
+    /// let hits = println!("counter ready").ret_cell(0u32);
+    /// hits.set(hits.get() + 1);
+    /// assert_eq!(hits.get(), 1);
+    /// ```
+    #[must_use]
+    #[inline]
+    fn ret_cell<T>(self, value: T) -> Cell<T> {
+        Cell::new(value)
+    }
+
+    /// Returns `Cell::new(T::default())`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unit_ext::*;
+    /// use std::cell::Cell;
+    ///
+    /// let flag: Cell<bool> = ().ret_cell_default();
+    /// assert!(!flag.get());
+    /// ```
+    #[must_use]
+    #[inline]
+    fn ret_cell_default<T: Default>(self) -> Cell<T> {
+        self.ret_cell(T::default())
+    }
+
+    /// Returns `RefCell::new(value)`.
+    ///
+    /// The usual borrow rules are enforced at runtime:
+    ///
+    /// ```should_panic
+    /// use unit_ext::*;
+    ///
+    /// let cell = ().ret_ref_cell(vec![1]);
+    /// let _reader = cell.borrow();
+    /// cell.borrow_mut().push(2); // already borrowed: panics
+    /// ```
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unit_ext::*;
+    ///
+    /// let log = println!("log ready").ret_ref_cell(Vec::new());
+    /// log.borrow_mut().push("started");
+    /// assert_eq!(*log.borrow(), ["started"]);
+    /// ```
+    #[must_use]
+    #[inline]
+    fn ret_ref_cell<T>(self, value: T) -> RefCell<T> {
+        RefCell::new(value)
+    }
+
+    /// Returns `RefCell::new(T::default())`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unit_ext::*;
+    /// use std::cell::RefCell;
+    ///
+    /// let s: RefCell<String> = ().ret_ref_cell_default();
+    /// s.borrow_mut().push_str("hi");
+    /// assert_eq!(&*s.borrow(), "hi");
+    /// ```
+    #[must_use]
+    #[inline]
+    fn ret_ref_cell_default<T: Default>(self) -> RefCell<T> {
+        self.ret_ref_cell(T::default())
+    }
+
+    /// Returns `U::from(value)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unit_ext::*;
+    ///
+    /// let wide = println!("widening").ret_from::<_, u64>(7u32);
+    /// assert_eq!(wide, u64::from(7u32));
+    ///
+    /// let owned: String = ().ret_from("borrowed");
+    /// assert_eq!(owned, String::from("borrowed"));
+    /// ```
+    #[must_use]
+    #[inline]
+    fn ret_from<T, U: From<T>>(self, value: T) -> U {
+        U::from(value)
+    }
+
+    /// Returns `value.into()`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unit_ext::*;
+    ///
+    /// let wide = ().ret_into::<_, i64>(-3i8);
+    /// assert_eq!(wide, i64::from(-3i8));
+    ///
+    /// let boxed: Box<str> = println!("boxing").ret_into("abc");
+    /// assert_eq!(&*boxed, "abc");
+    /// ```
+    #[must_use]
+    #[inline]
+    fn ret_into<T: Into<U>, U>(self, value: T) -> U {
+        value.into()
+    }
+
+    /// Returns `U::try_from(value)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unit_ext::*;
+    ///
+    /// let fits = println!("narrowing").ret_try_from::<_, u8>(200u32);
+    /// assert_eq!(fits, Ok(200));
+    ///
+    /// let overflow = ().ret_try_from::<_, u8>(300u32);
+    /// assert!(overflow.is_err());
+    /// ```
+    #[must_use]
+    #[inline]
+    fn ret_try_from<T, U: TryFrom<T>>(
+        self,
+        value: T,
+    ) -> Result<U, U::Error> {
+        U::try_from(value)
+    }
+
+    /// Returns `value.try_into()`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unit_ext::*;
+    ///
+    /// let header: Result<[u8; 2], _> = ().ret_try_into(&[1u8, 2][..]);
+    /// assert_eq!(header.ok(), Some([1, 2]));
+    ///
+    /// let short: Result<[u8; 4], _> = ().ret_try_into(&[1u8, 2][..]);
+    /// assert!(short.is_err());
+    /// ```
+    #[must_use]
+    #[inline]
+    fn ret_try_into<T: TryInto<U>, U>(
+        self,
+        value: T,
+    ) -> Result<U, T::Error> {
+        value.try_into()
+    }
+
+    /// Returns `s.parse::<T>()`.
+    ///
+    /// Keeps the parse in expression position after a side effect.
+    ///
+    /// # Errors
+    ///
+    /// Fails with `T::Err` if `s` cannot be parsed as a `T`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unit_ext::*;
+    /// use std::num::{IntErrorKind, ParseIntError};
+    ///
+    /// let port = println!("parsing port").ret_parse::<u16>("8080");
+    /// assert_eq!(port, Ok(8080));
+    ///
+    /// let err: ParseIntError = ().ret_parse::<u8>("300").unwrap_err();
+    /// assert_eq!(err.kind(), &IntErrorKind::PosOverflow);
+    /// assert!(().ret_parse::<f64>("nan?").is_err());
+    /// ```
+    #[must_use]
+    #[inline]
+    fn ret_parse<T: FromStr>(self, s: &str) -> Result<T, T::Err> {
+        s.parse()
+    }
+
+    /// Returns `true`.
+    ///
+    /// Reads well as the tail of a predicate that has to run a side
+    /// effect first. Like every helper here this is a trait method,
+    /// so it cannot be `const`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unit_ext::*;
+    ///
+    /// trait Check {
+    ///     fn passes(&self, log: &mut Vec<&'static str>) -> bool;
+    /// }
+    ///
+    /// struct Always;
+    /// struct Never;
+    ///
+    /// impl Check for Always {
+    ///     fn passes(&self, log: &mut Vec<&'static str>) -> bool {
+    ///         log.push("always").ret_true()
+    ///     }
+    /// }
+    ///
+    /// impl Check for Never {
+    ///     fn passes(&self, log: &mut Vec<&'static str>) -> bool {
+    ///         log.push("never").ret_false()
+    ///     }
+    /// }
+    ///
+    /// let mut log = Vec::new();
+    /// assert!(Always.passes(&mut log));
+    /// assert!(!Never.passes(&mut log));
+    /// assert_eq!(log, ["always", "never"]);
+    /// ```
+    #[must_use]
+    #[inline]
+    fn ret_true(self) -> bool {
+        true
+    }
+
+    /// Returns `false`.
+    ///
+    /// See [`ret_true`](UnitExt::ret_true) for an example.
+    #[must_use]
+    #[inline]
+    fn ret_false(self) -> bool {
+        false
+    }
+
+    /// Returns `(a, b)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unit_ext::*;
+    ///
+    /// let (key, value) = println!("pairing").ret_tuple2("id", 7);
+    /// assert_eq!((key, value), ("id", 7));
+    /// ```
+    #[must_use]
+    #[inline]
+    fn ret_tuple2<A, B>(self, a: A, b: B) -> (A, B) {
+        (a, b)
+    }
+
+    /// Returns `(a, b, c)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unit_ext::*;
+    ///
+    /// let (x, y, z) = ().ret_tuple3(1, 'b', "c");
+    /// assert_eq!(x, 1);
+    /// assert_eq!(y, 'b');
+    /// assert_eq!(z, "c");
+    /// ```
+    #[must_use]
+    #[inline]
+    fn ret_tuple3<A, B, C>(self, a: A, b: B, c: C) -> (A, B, C) {
+        (a, b, c)
+    }
+
+    /// Alias for [`ret_tuple2`](UnitExt::ret_tuple2).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unit_ext::*;
+    ///
+    /// let pairs: Vec<(usize, char)> = "ab"
+    ///     .chars()
+    ///     .enumerate()
+    ///     .map(|(i, c)| ().ret_pair(i, c))
+    ///     .collect();
+    /// assert_eq!(pairs, [(0, 'a'), (1, 'b')]);
+    /// ```
+    #[must_use]
+    #[inline]
+    fn ret_pair<A, B>(self, a: A, b: B) -> (A, B) {
+        self.ret_tuple2(a, b)
+    }
+
+    /// Returns [`Ordering::Less`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unit_ext::*;
+    /// use std::cmp::Ordering;
+    ///
+    /// #[derive(PartialEq, Eq)]
+    /// struct Version(u32, u32);
+    ///
+    /// impl PartialOrd for Version {
+    ///     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+    ///         Some(self.cmp(other))
+    ///     }
+    /// }
+    ///
+    /// impl Ord for Version {
+    ///     fn cmp(&self, other: &Self) -> Ordering {
+    ///         if self.0 != other.0 {
+    ///             if self.0 < other.0 {
+    ///                 println!("major behind").ret_less()
+    ///             } else {
+    ///                 println!("major ahead").ret_greater()
+    ///             }
+    ///         } else if self.1 < other.1 {
+    ///             ().ret_less()
+    ///         } else if self.1 > other.1 {
+    ///             ().ret_greater()
+    ///         } else {
+    ///             ().ret_equal()
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// let versions = [(1, 0), (1, 2), (2, 0), (0, 9)];
+    /// for a in versions {
+    ///     for b in versions {
+    ///         let (va, vb) = (Version(a.0, a.1), Version(b.0, b.1));
+    ///         assert_eq!(va.cmp(&vb), a.cmp(&b));
+    ///         assert_eq!(va.partial_cmp(&vb), a.partial_cmp(&b));
+    ///     }
+    /// }
+    /// ```
+    #[must_use]
+    #[inline]
+    fn ret_less(self) -> Ordering {
+        Ordering::Less
+    }
+
+    /// Returns [`Ordering::Greater`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unit_ext::*;
+    /// use std::cmp::Ordering;
+    /// assert_eq!(().ret_greater(), Ordering::Greater);
+    /// ```
+    #[must_use]
+    #[inline]
+    fn ret_greater(self) -> Ordering {
+        Ordering::Greater
+    }
+
+    /// Returns [`Ordering::Equal`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unit_ext::*;
+    /// use std::cmp::Ordering;
+    /// assert_eq!(().ret_equal(), Ordering::Equal);
+    /// ```
+    #[must_use]
+    #[inline]
+    fn ret_equal(self) -> Ordering {
+        Ordering::Equal
+    }
+
+    /// Returns [`a.min(b)`](Ord::min).
+    ///
+    /// On a tie `a` is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unit_ext::*;
+    ///
+    /// assert_eq!(println!("lower").ret_min_of(3, 8), 3.min(8));
+    /// assert_eq!(().ret_min_of(-1, -1), -1);
+    /// assert_eq!(().ret_min_of(i8::MIN, 0), i8::MIN);
+    /// ```
+    #[must_use]
+    #[inline]
+    fn ret_min_of<T: Ord>(self, a: T, b: T) -> T {
+        a.min(b)
+    }
+
+    /// Returns [`a.max(b)`](Ord::max).
+    ///
+    /// On a tie `b` is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unit_ext::*;
+    ///
+    /// assert_eq!(println!("upper").ret_max_of(3, 8), 3.max(8));
+    /// assert_eq!(().ret_max_of(u8::MAX, 0), u8::MAX);
+    /// assert_eq!(().ret_max_of("a", "a"), "a");
+    /// ```
+    #[must_use]
+    #[inline]
+    fn ret_max_of<T: Ord>(self, a: T, b: T) -> T {
+        a.max(b)
+    }
+
+    /// Returns [`value.clamp(min, max)`](Ord::clamp).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `min > max`:
+    ///
+    /// ```should_panic
+    /// use unit_ext::*;
+    /// let _ = ().ret_clamp_of(5, 10, 0);
+    /// ```
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unit_ext::*;
+    ///
+    /// let clamp = |v: i32| println!("clamping").ret_clamp_of(v, 0, 10);
+    /// assert_eq!(clamp(-5), 0);
+    /// assert_eq!(clamp(0), 0);
+    /// assert_eq!(clamp(7), 7.clamp(0, 10));
+    /// assert_eq!(clamp(10), 10);
+    /// assert_eq!(clamp(99), 10);
+    /// assert_eq!(().ret_clamp_of(4, 4, 4), 4);
+    /// ```
+    #[must_use]
+    #[inline]
+    fn ret_clamp_of<T: Ord>(self, value: T, min: T, max: T) -> T {
+        value.clamp(min, max)
+    }
+
+    /// Returns `Duration::from_secs(secs)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unit_ext::*;
+    /// use std::time::Duration;
+    ///
+    /// let backoff = println!("retrying").ret_secs(2);
+    /// assert_eq!(backoff, Duration::from_secs(2));
+    /// ```
+    #[must_use]
+    #[inline]
+    fn ret_secs(self, secs: u64) -> Duration {
+        Duration::from_secs(secs)
+    }
+
+    /// Returns `Duration::from_millis(millis)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unit_ext::*;
+    /// assert_eq!(().ret_millis(500).as_secs_f64(), 0.5);
+    /// ```
+    #[must_use]
+    #[inline]
+    fn ret_millis(self, millis: u64) -> Duration {
+        Duration::from_millis(millis)
+    }
+
+    /// Returns `Duration::from_micros(micros)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unit_ext::*;
+    /// assert_eq!(().ret_micros(1_500).as_millis(), 1);
+    /// ```
+    #[must_use]
+    #[inline]
+    fn ret_micros(self, micros: u64) -> Duration {
+        Duration::from_micros(micros)
+    }
+
+    /// Returns `Duration::from_nanos(nanos)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unit_ext::*;
+    /// assert_eq!(().ret_nanos(2_000).as_micros(), 2);
+    /// ```
+    #[must_use]
+    #[inline]
+    fn ret_nanos(self, nanos: u64) -> Duration {
+        Duration::from_nanos(nanos)
+    }
+
+    /// Returns [`Duration::ZERO`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unit_ext::*;
+    ///
+    /// let d = ().ret_duration_zero();
+    /// assert!(d.is_zero());
+    /// assert_eq!(d.as_nanos(), 0);
+    /// ```
+    #[must_use]
+    #[inline]
+    fn ret_duration_zero(self) -> Duration {
+        Duration::ZERO
+    }
+
+    /// Returns `start..end`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unit_ext::*;
+    ///
+    /// let bounds = Some(4).map(|n| println!("{n} rows").ret_range(0, n));
+    /// let rows: Vec<_> = bounds.into_iter().flatten().collect();
+    /// assert_eq!(rows, (0..4).collect::<Vec<_>>());
+    /// ```
+    #[must_use]
+    #[inline]
+    fn ret_range<T>(self, start: T, end: T) -> Range<T> {
+        start..end
+    }
+
+    /// Returns `start..=end`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unit_ext::*;
+    ///
+    /// let sum: u32 = ().ret_range_inclusive(1, 4).sum();
+    /// assert_eq!(sum, (1..=4).sum());
+    /// assert_eq!(().ret_range_inclusive('a', 'c').count(), 3);
+    /// ```
+    #[must_use]
+    #[inline]
+    fn ret_range_inclusive<T>(
+        self,
+        start: T,
+        end: T,
+    ) -> RangeInclusive<T> {
+        start..=end
+    }
+
+    /// Returns `start..`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unit_ext::*;
+    ///
+    /// let items = [10, 20, 30, 40];
+    /// assert_eq!(items[().ret_range_from(2)], [30, 40]);
+    /// ```
+    #[must_use]
+    #[inline]
+    fn ret_range_from<T>(self, start: T) -> RangeFrom<T> {
+        start..
+    }
+
+    /// Returns `..end`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unit_ext::*;
+    ///
+    /// let items = [10, 20, 30, 40];
+    /// assert_eq!(items[().ret_range_to(2)], [10, 20]);
+    /// ```
+    #[must_use]
+    #[inline]
+    fn ret_range_to<T>(self, end: T) -> RangeTo<T> {
+        ..end
+    }
+
+    /// Returns `..`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unit_ext::*;
+    ///
+    /// let items = [10, 20, 30, 40];
+    /// assert_eq!(items[().ret_range_full()], items);
+    /// ```
+    #[must_use]
+    #[inline]
+    fn ret_range_full(self) -> RangeFull {
+        ..
+    }
+
+    ret_non_zero! {
+        ret_non_zero_u8 => NonZeroU8(u8),
+        ret_non_zero_u16 => NonZeroU16(u16),
+        ret_non_zero_u32 => NonZeroU32(u32),
+        ret_non_zero_u64 => NonZeroU64(u64),
+        ret_non_zero_u128 => NonZeroU128(u128),
+        ret_non_zero_usize => NonZeroUsize(usize),
+        ret_non_zero_i8 => NonZeroI8(i8),
+        ret_non_zero_i16 => NonZeroI16(i16),
+        ret_non_zero_i32 => NonZeroI32(i32),
+        ret_non_zero_i64 => NonZeroI64(i64),
+        ret_non_zero_i128 => NonZeroI128(i128),
+        ret_non_zero_isize => NonZeroIsize(isize),
+    }
+
+    /// Returns `Wrapping(value)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unit_ext::*;
+    /// use std::num::Wrapping;
+    ///
+    /// let counter = println!("counter reset").ret_wrapping(u8::MAX);
+    /// assert_eq!(counter + Wrapping(1), Wrapping(0));
+    ///
+    /// let hash = ().ret_wrapping(0u32).pipe(|h| h * Wrapping(31));
+    /// assert_eq!(hash.0, 0);
+    /// ```
+    #[must_use]
+    #[inline]
+    fn ret_wrapping<T>(self, value: T) -> Wrapping<T> {
+        Wrapping(value)
+    }
+
+    /// Returns `Saturating(value)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unit_ext::*;
+    /// use std::num::Saturating;
+    ///
+    /// let level = println!("volume max").ret_saturating(250u8);
+    /// assert_eq!(level + Saturating(10), Saturating(u8::MAX));
+    ///
+    /// let low = ().ret_saturating(-120i8) - Saturating(20);
+    /// assert_eq!(low, Saturating(i8::MIN));
+    /// ```
+    #[must_use]
+    #[inline]
+    fn ret_saturating<T>(self, value: T) -> Saturating<T> {
+        Saturating(value)
+    }
+
+    /// Returns `MaybeUninit::uninit()`.
+    ///
+    /// This is safe to call; the contents are simply not initialized
+    /// yet. Before calling [`assume_init`](MaybeUninit::assume_init)
+    /// (or reading through a pointer to it) the caller must have
+    /// written a valid `T`, otherwise the behaviour is undefined.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unit_ext::*;
+    ///
+    /// let mut slot = println!("reserving").ret_maybe_uninit::<u32>();
+    /// slot.write(7);
+    /// // SAFETY: `slot` was initialized by the `write` above.
+    /// let value = unsafe { slot.assume_init() };
+    /// assert_eq!(value, 7);
+    /// ```
+    #[must_use]
+    #[inline]
+    fn ret_maybe_uninit<T>(self) -> MaybeUninit<T> {
+        MaybeUninit::uninit()
+    }
+
+    /// Returns `ManuallyDrop::new(value)`.
+    ///
+    /// The wrapped value is not dropped automatically, so it is only
+    /// dropped where the caller decides to.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unit_ext::*;
+    /// use std::mem::ManuallyDrop;
+    ///
+    /// let n = ().ret_manually_drop(5);
+    /// assert_eq!(ManuallyDrop::into_inner(n), 5);
+    /// ```
+    #[must_use]
+    #[inline]
+    fn ret_manually_drop<T>(self, value: T) -> ManuallyDrop<T> {
+        ManuallyDrop::new(value)
+    }
+
+    /// Returns `Pin::new(ptr)`.
+    ///
+    /// Only pointers to [`Unpin`] targets can be pinned this way; for
+    /// anything else use `ret_pin_box` (with the `alloc` feature) or
+    /// pin in place with [`core::pin::pin!`].
+    ///
+    /// ```compile_fail
+    /// use unit_ext::*;
+    /// use std::marker::PhantomPinned;
+    ///
+    /// let mut pinned = PhantomPinned;
+    /// let _ = ().ret_pin(&mut pinned);
+    /// ```
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unit_ext::*;
+    /// use std::pin::Pin;
+    ///
+    /// let mut n = 5;
+    /// let mut pinned: Pin<&mut i32> = println!("pinning").ret_pin(&mut n);
+    /// *pinned += 1;
+    /// assert_eq!(n, 6);
+    /// ```
+    #[must_use]
+    #[inline]
+    fn ret_pin<P>(self, ptr: P) -> Pin<P>
+    where
+        P: Deref,
+        P::Target: Unpin,
+    {
+        Pin::new(ptr)
+    }
+
+    /// Returns `iter::once(value)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unit_ext::*;
+    ///
+    /// let filled: Vec<u8> = [Some(1), None, Some(3)]
+    ///     .into_iter()
+    ///     .flat_map(|x| match x {
+    ///         Some(n) => ().ret_iter_once(n),
+    ///         None => println!("using default").ret_iter_once(0),
+    ///     })
+    ///     .collect();
+    /// assert_eq!(filled, [1, 0, 3]);
+    /// assert!(().ret_iter_once(5).eq(std::iter::once(5)));
+    /// ```
+    #[must_use]
+    #[inline]
+    fn ret_iter_once<T>(self, value: T) -> Once<T> {
+        iter::once(value)
+    }
+
+    /// Returns `iter::empty()`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unit_ext::*;
+    /// use std::iter::Empty;
+    ///
+    /// let none: Empty<u8> = ().ret_iter_empty();
+    /// assert_eq!(none.count(), 0);
+    ///
+    /// let items: Vec<u8> = ().ret_iter_empty().collect();
+    /// assert!(items.is_empty());
+    /// ```
+    #[must_use]
+    #[inline]
+    fn ret_iter_empty<T>(self) -> Empty<T> {
+        iter::empty()
+    }
+
+    /// Returns `iter::repeat(value)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unit_ext::*;
+    ///
+    /// let fixture: Vec<_> =
+    ///     println!("fixture").ret_iter_repeat("x").take(3).collect();
+    /// assert_eq!(fixture, ["x", "x", "x"]);
+    /// ```
+    #[must_use]
+    #[inline]
+    fn ret_iter_repeat<T: Clone>(self, value: T) -> Repeat<T> {
+        iter::repeat(value)
+    }
+
+    /// Returns `iter::repeat_with(f)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unit_ext::*;
+    ///
+    /// let mut next = 0;
+    /// let ids: Vec<u32> = println!("ids")
+    ///     .ret_iter_repeat_with(|| {
+    ///         next += 1;
+    ///         next
+    ///     })
+    ///     .take(3)
+    ///     .collect();
+    /// assert_eq!(ids, [1, 2, 3]);
+    /// ```
+    #[must_use]
+    #[inline]
+    fn ret_iter_repeat_with<T, F: FnMut() -> T>(
+        self,
+        f: F,
+    ) -> RepeatWith<F> {
+        iter::repeat_with(f)
+    }
+
+    /// Returns [`iter::repeat_n(value, n)`](iter::repeat_n), an
+    /// iterator yielding `value` exactly `n` times.
+    ///
+    /// The finite form of [`ret_iter_repeat`](UnitExt::ret_iter_repeat)
+    /// that needs no `.take(n)`. `repeat_n` was stabilised in Rust
+    /// 1.82; edition 2024 already requires 1.85, so no gate is needed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unit_ext::*;
+    ///
+    /// let buffer: Vec<u8> = println!("init").ret_repeat_n(0, 4).collect();
+    /// assert_eq!(buffer, [0, 0, 0, 0]);
+    /// assert_eq!(().ret_repeat_n("x", 0).count(), 0);
+    /// ```
+    #[must_use]
+    #[inline]
+    fn ret_repeat_n<T: Clone>(
+        self,
+        value: T,
+        n: usize,
+    ) -> RepeatN<T> {
+        iter::repeat_n(value, n)
+    }
+
+    /// Returns `a.into_iter().zip(b)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unit_ext::*;
+    ///
+    /// let keys = ["a", "b", "c"];
+    /// let values = vec![1, 2];
+    /// let merged: Vec<_> = println!("merging").ret_zip(keys, values).collect();
+    /// assert_eq!(merged, keys.into_iter().zip([1, 2]).collect::<Vec<_>>());
+    /// assert_eq!(merged, [("a", 1), ("b", 2)]);
+    /// ```
+    #[must_use]
+    #[inline]
+    fn ret_zip<A: IntoIterator, B: IntoIterator>(
+        self,
+        a: A,
+        b: B,
+    ) -> Zip<A::IntoIter, B::IntoIter> {
+        a.into_iter().zip(b)
+    }
+
+    /// Returns `a.into_iter().chain(b)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unit_ext::*;
+    ///
+    /// let head = [1, 2];
+    /// let tail = vec![3];
+    /// let all: Vec<_> = ().ret_chain_iters(head, tail).collect();
+    /// assert_eq!(all, [1, 2].into_iter().chain([3]).collect::<Vec<_>>());
+    /// ```
+    #[must_use]
+    #[inline]
+    fn ret_chain_iters<A, B>(
+        self,
+        a: A,
+        b: B,
+    ) -> Chain<A::IntoIter, B::IntoIter>
+    where
+        A: IntoIterator,
+        B: IntoIterator<Item = A::Item>,
+    {
+        a.into_iter().chain(b)
+    }
+
+    /// Returns `iter.into_iter().enumerate()`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unit_ext::*;
+    ///
+    /// let indexed: Vec<_> = println!("indexing").ret_enumerate("ab".chars()).collect();
+    /// assert_eq!(indexed, [(0, 'a'), (1, 'b')]);
+    /// assert_eq!(().ret_enumerate(vec![7]).next(), Some((0, 7)));
+    /// ```
+    #[must_use]
+    #[inline]
+    fn ret_enumerate<I: IntoIterator>(
+        self,
+        iter: I,
+    ) -> Enumerate<I::IntoIter> {
+        iter.into_iter().enumerate()
+    }
+
+    /// Returns `iter.into_iter().peekable()`.
+    ///
+    /// Handy at the start of a hand-written parser.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unit_ext::*;
+    ///
+    /// let mut tokens = println!("lexing").ret_peekable("12+3".chars());
+    /// let mut number = 0;
+    /// while let Some(digit) = tokens.peek().and_then(|c| c.to_digit(10)) {
+    ///     number = number * 10 + digit;
+    ///     tokens.next();
+    /// }
+    /// assert_eq!(number, 12);
+    /// assert_eq!(tokens.peek(), Some(&'+'));
+    /// assert_eq!(tokens.next(), Some('+'));
+    /// ```
+    #[must_use]
+    #[inline]
+    fn ret_peekable<I: IntoIterator>(
+        self,
+        iter: I,
+    ) -> Peekable<I::IntoIter> {
+        iter.into_iter().peekable()
+    }
+
+    /// Returns `iter.into_iter().collect()`.
+    ///
+    /// The target is usually inferred from the binding. `FromIterator`
+    /// lives in `core`, so this needs no feature: `Vec`, `String` and
+    /// the other heap collections are simply the most common targets.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unit_ext::*;
+    /// use std::collections::BTreeSet;
+    ///
+    /// let squares: Vec<u32> = println!("collecting").ret_collect((1..4).map(|n| n * n));
+    /// assert_eq!(squares, [1, 4, 9]);
+    ///
+    /// let word: String = ().ret_collect(['h', 'i']);
+    /// assert_eq!(word, "hi");
+    ///
+    /// let unique: BTreeSet<u32> = ().ret_collect(vec![3, 1, 3]);
+    /// assert_eq!(unique.into_iter().collect::<Vec<_>>(), [1, 3]);
+    ///
+    /// let total = ().ret_collect::<Option<Vec<u8>>, _, _>([Some(1), None]);
+    /// assert_eq!(total, None);
+    /// ```
+    #[must_use]
+    #[inline]
+    fn ret_collect<C, T, I>(self, iter: I) -> C
+    where
+        C: FromIterator<T>,
+        I: IntoIterator<Item = T>,
+    {
+        iter.into_iter().collect()
+    }
+
+    ret_atomic! {
+        "8": ret_atomic_bool => AtomicBool(bool = true),
+        "8": ret_atomic_u8 => AtomicU8(u8 = 8),
+        "16": ret_atomic_u16 => AtomicU16(u16 = 16),
+        "32": ret_atomic_u32 => AtomicU32(u32 = 32),
+        "64": ret_atomic_u64 => AtomicU64(u64 = 64),
+        "ptr": ret_atomic_usize => AtomicUsize(usize = 0),
+        "8": ret_atomic_i8 => AtomicI8(i8 = -8),
+        "16": ret_atomic_i16 => AtomicI16(i16 = -16),
+        "32": ret_atomic_i32 => AtomicI32(i32 = -32),
+        "64": ret_atomic_i64 => AtomicI64(i64 = -64),
+        "ptr": ret_atomic_isize => AtomicIsize(isize = -1),
+    }
+
+    /// Returns [`core::any::type_name::<T>()`](core::any::type_name).
+    ///
+    /// The string is meant for diagnostics only: its exact contents
+    /// are not guaranteed to be stable across compiler versions.
+    /// `T` may be unsized.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unit_ext::*;
+    ///
+    /// struct Expected;
+    ///
+    /// assert!(().ret_type_name::<Expected>().ends_with("Expected"));
+    /// assert!(().ret_type_name::<Vec<u8>>().contains("Vec"));
+    /// assert!(().ret_type_name::<str>().contains("str"));
+    ///
+    /// let primitives = [
+    ///     ().ret_type_name::<bool>(),
+    ///     ().ret_type_name::<char>(),
+    ///     ().ret_type_name::<u8>(),
+    ///     ().ret_type_name::<i128>(),
+    ///     ().ret_type_name::<f64>(),
+    ///     ().ret_type_name::<()>(),
+    /// ];
+    /// assert!(primitives.iter().all(|name| !name.is_empty()));
+    /// assert_eq!(primitives[2], "u8");
+    /// ```
+    #[must_use]
+    #[inline]
+    fn ret_type_name<T: ?Sized>(self) -> &'static str {
+        core::any::type_name::<T>()
+    }
+
+    /// Returns `c`.
+    ///
+    /// Same as [`ret`](UnitExt::ret) with the type fixed to `char`, so
+    /// literals need no annotation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unit_ext::*;
+    ///
+    /// let sep = println!("joining").ret_char(',');
+    /// assert_eq!(format!("a{sep}b"), "a,b");
+    /// ```
+    #[must_use]
+    #[inline]
+    fn ret_char(self, c: char) -> char {
+        c
+    }
+
+    /// Returns [`char::from_u32(n)`](char::from_u32).
+    ///
+    /// `None` for surrogates (`0xD800..=0xDFFF`) and values above
+    /// `char::MAX`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unit_ext::*;
+    ///
+    /// assert_eq!(println!("mapping").ret_char_from_u32(0x41), Some('A'));
+    /// assert_eq!(().ret_char_from_u32(0xFFFD), Some('\u{FFFD}'));
+    /// assert_eq!(().ret_char_from_u32(0xD800), None);
+    /// assert_eq!(().ret_char_from_u32(0xDFFF), None);
+    /// assert_eq!(().ret_char_from_u32(0x11_0000), None);
+    /// ```
+    #[must_use]
+    #[inline]
+    fn ret_char_from_u32(self, n: u32) -> Option<char> {
+        char::from_u32(n)
+    }
+
+    /// Returns [`char::from_digit(digit, radix)`](char::from_digit).
+    ///
+    /// `None` if `digit` is not below `radix`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `radix` is greater than 36:
+    ///
+    /// ```should_panic
+    /// use unit_ext::*;
+    /// let _ = ().ret_char_from_digit(1, 37);
+    /// ```
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unit_ext::*;
+    ///
+    /// assert_eq!(().ret_char_from_digit(7, 10), Some('7'));
+    /// assert_eq!(().ret_char_from_digit(11, 16), Some('b'));
+    /// assert_eq!(().ret_char_from_digit(2, 2), None);
+    ///
+    /// for radix in 2..=36 {
+    ///     let top = ().ret_char_from_digit(radix - 1, radix).unwrap();
+    ///     assert_eq!(top.to_digit(radix), Some(radix - 1));
+    /// }
+    /// ```
+    #[must_use]
+    #[inline]
+    fn ret_char_from_digit(
+        self,
+        digit: u32,
+        radix: u32,
+    ) -> Option<char> {
+        char::from_digit(digit, radix)
+    }
+
+    /// Returns `value` as a one-element slice, via
+    /// [`slice::from_ref`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unit_ext::*;
+    ///
+    /// fn total(items: &[u32]) -> u32 {
+    ///     items.iter().sum()
+    /// }
+    ///
+    /// let single = 7;
+    /// let items = println!("one item").ret_slice_of(&single);
+    /// assert_eq!(items.len(), 1);
+    /// assert_eq!(items[0], single);
+    /// assert_eq!(total(items), 7);
+    /// ```
+    #[must_use]
+    #[inline]
+    fn ret_slice_of<T>(self, value: &T) -> &[T] {
+        slice::from_ref(value)
+    }
+
+    /// Returns `value` as a mutable one-element slice, via
+    /// [`slice::from_mut`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unit_ext::*;
+    ///
+    /// fn bump_all(items: &mut [u32]) {
+    ///     items.iter_mut().for_each(|n| *n += 1);
+    /// }
+    ///
+    /// let mut single = 7;
+    /// let items = ().ret_slice_of_mut(&mut single);
+    /// assert_eq!(items.len(), 1);
+    /// bump_all(items);
+    /// items[0] *= 2;
+    /// assert_eq!(single, 16);
+    /// ```
+    #[must_use]
+    #[inline]
+    fn ret_slice_of_mut<T>(self, value: &mut T) -> &mut [T] {
+        slice::from_mut(value)
+    }
+
+    /// Returns `[value; N]`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unit_ext::*;
+    ///
+    /// let buf = println!("init").ret_array::<u8, 16>(0);
+    /// assert_eq!(buf, [0; 16]);
+    ///
+    /// let one: [char; 1] = ().ret_array('x');
+    /// assert_eq!(one, ['x']);
+    /// let none: [char; 0] = ().ret_array('x');
+    /// assert!(none.is_empty());
+    /// ```
+    #[must_use]
+    #[inline]
+    fn ret_array<T: Copy, const N: usize>(self, value: T) -> [T; N] {
+        [value; N]
+    }
+
+    /// Returns an array of `N` copies of `T::default()`.
+    ///
+    /// Each element is built separately, so `T` does not need to be
+    /// `Copy`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unit_ext::*;
+    ///
+    /// let names: [String; 2] = ().ret_array_default();
+    /// assert_eq!(names, ["", ""]);
+    /// assert_eq!(().ret_array_default::<u8, 1>(), [0]);
+    /// assert_eq!(().ret_array_default::<u8, 0>(), []);
+    /// ```
+    #[must_use]
+    #[inline]
+    fn ret_array_default<T: Default, const N: usize>(self) -> [T; N] {
+        self.ret_array_from_fn(|_| T::default())
+    }
+
+    /// Returns [`array::from_fn(f)`](array::from_fn), calling `f`
+    /// with each index in order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unit_ext::*;
+    ///
+    /// let squares: [usize; 4] = ().ret_array_from_fn(|i| i * i);
+    /// assert_eq!(squares, [0, 1, 4, 9]);
+    ///
+    /// let labels: [String; 1] = ().ret_array_from_fn(|i| format!("#{i}"));
+    /// assert_eq!(labels, ["#0"]);
+    ///
+    /// let mut calls = 0;
+    /// let empty: [u8; 0] = ().ret_array_from_fn(|_| {
+    ///     calls += 1;
+    ///     0
+    /// });
+    /// assert_eq!((empty.len(), calls), (0, 0));
+    /// ```
+    #[must_use]
+    #[inline]
+    fn ret_array_from_fn<T, const N: usize, F>(self, f: F) -> [T; N]
+    where
+        F: FnMut(usize) -> T,
+    {
+        array::from_fn(f)
+    }
+
+    /// Returns [`str::from_utf8(bytes)`](core::str::from_utf8).
+    ///
+    /// # Errors
+    ///
+    /// Fails with [`Utf8Error`] if `bytes` is not valid UTF-8.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unit_ext::*;
+    ///
+    /// assert_eq!(println!("decoding").ret_utf8_str(b"ping"), Ok("ping"));
+    ///
+    /// let input = "grüße";
+    /// let bytes = ().ret_bytes(input);
+    /// assert_eq!(().ret_utf8_str(bytes), Ok(input));
+    ///
+    /// let err = ().ret_utf8_str(&bytes[..3]).unwrap_err();
+    /// assert_eq!(err.valid_up_to(), 2);
+    /// assert_eq!(err.error_len(), None);
+    ///
+    /// let err = ().ret_utf8_str(b"ab\xFFcd").unwrap_err();
+    /// assert_eq!(err.error_len(), Some(1));
+    /// ```
+    #[doc(alias = "ret_str_from_utf8")]
+    #[must_use]
+    #[inline]
+    fn ret_utf8_str(self, bytes: &[u8]) -> Result<&str, Utf8Error> {
+        core::str::from_utf8(bytes)
+    }
+
+    /// Returns [`s.as_bytes()`](str::as_bytes).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unit_ext::*;
+    ///
+    /// fn checksum(bytes: &[u8]) -> u32 {
+    ///     bytes.iter().map(|&b| u32::from(b)).sum()
+    /// }
+    ///
+    /// let bytes = println!("validated").ret_bytes("ab");
+    /// assert_eq!(bytes, b"ab");
+    /// assert_eq!(checksum(bytes), 97 + 98);
+    /// ```
+    #[must_use]
+    #[inline]
+    fn ret_bytes(self, s: &str) -> &[u8] {
+        s.as_bytes()
+    }
+
+    /// Returns [`PhantomData<T>`].
+    ///
+    /// Shorter than `ret_default::<PhantomData<T>>()` and needs no
+    /// extra import at the call site. `T` may be unsized.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unit_ext::*;
+    /// use std::marker::PhantomData;
+    ///
+    /// struct Id<T: ?Sized> {
+    ///     raw: u32,
+    ///     _marker: PhantomData<T>,
+    /// }
+    ///
+    /// let sized: Id<String> = Id { raw: 1, _marker: ().ret_phantom() };
+    /// let dynamic: Id<str> = Id { raw: 2, _marker: ().ret_phantom() };
+    /// let _: PhantomData<[u8]> = ().ret_phantom::<[u8]>();
+    /// assert_eq!(sized.raw + dynamic.raw, 3);
+    /// ```
+    #[must_use]
+    #[inline]
+    fn ret_phantom<T: ?Sized>(self) -> PhantomData<T> {
+        PhantomData
+    }
+
+    /// Returns `Box::new(value)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unit_ext::*;
+    ///
+    /// trait Shape {
+    ///     fn area(&self) -> f64;
+    /// }
+    /// struct Square(f64);
+    /// impl Shape for Square {
+    ///     fn area(&self) -> f64 {
+    ///         self.0 * self.0
+    ///     }
+    /// }
+    ///
+    /// let shape: Box<dyn Shape> =
+    ///     println!("building square").ret_box(Square(2.0));
+    /// assert_eq!(shape.area(), 4.0);
+    /// assert_eq!(().ret_box(5), Box::new(5));
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    #[inline]
+    fn ret_box<T>(self, value: T) -> Box<T> {
+        Box::new(value)
+    }
+
+    /// Returns `Box::new(T::default())`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unit_ext::*;
+    /// let b: Box<Vec<u8>> = ().ret_box_default();
+    /// assert_eq!(b, Box::default());
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    #[inline]
+    fn ret_box_default<T: Default>(self) -> Box<T> {
+        self.ret_box(T::default())
+    }
+
+    /// Returns `Box::pin(value)`.
+    ///
+    /// Works for any `T`, including `!Unpin` futures.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unit_ext::*;
+    /// use std::future::Future;
+    /// use std::marker::PhantomPinned;
+    /// use std::pin::Pin;
+    /// use std::task::{Context, Poll, Waker};
+    ///
+    /// let _pinned = ().ret_pin_box(PhantomPinned);
+    ///
+    /// let mut fut: Pin<Box<dyn Future<Output = u8>>> =
+    ///     println!("spawning").ret_pin_box(async { 3 });
+    /// let mut cx = Context::from_waker(Waker::noop());
+    /// assert_eq!(fut.as_mut().poll(&mut cx), Poll::Ready(3));
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    #[inline]
+    fn ret_pin_box<T>(self, value: T) -> Pin<Box<T>> {
+        Box::pin(value)
+    }
+
+    /// Returns `Cow::Borrowed(value)`.
+    ///
+    /// The returned `Cow` borrows from `value` and lives as long as it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unit_ext::*;
+    /// use std::borrow::Cow;
+    ///
+    /// fn normalize(s: &str) -> Cow<'_, str> {
+    ///     if s.contains(' ') {
+    ///         ().ret_cow_owned(s.replace(' ', "_"))
+    ///     } else {
+    ///         println!("already normalized").ret_cow_borrowed(s)
+    ///     }
+    /// }
+    ///
+    /// assert!(matches!(normalize("a_b"), Cow::Borrowed("a_b")));
+    /// assert_eq!(normalize("a b"), "a_b");
+    ///
+    /// let bytes: Cow<[u8]> = ().ret_cow_borrowed(&[1, 2][..]);
+    /// assert_eq!(bytes.len(), 2);
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    #[inline]
+    fn ret_cow_borrowed<B: ?Sized + ToOwned>(
+        self,
+        value: &B,
+    ) -> Cow<'_, B> {
+        Cow::Borrowed(value)
+    }
+
+    /// Returns `Cow::Owned(value)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unit_ext::*;
+    /// use std::borrow::Cow;
+    ///
+    /// let s: Cow<str> = ().ret_cow_owned(String::from("owned"));
+    /// assert!(matches!(s, Cow::Owned(_)));
+    ///
+    /// let mut bytes: Cow<[u8]> = ().ret_cow_owned(vec![1, 2]);
+    /// bytes.to_mut().push(3);
+    /// assert_eq!(&*bytes, [1, 2, 3]);
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    #[inline]
+    fn ret_cow_owned<'a, B: ?Sized + ToOwned>(
+        self,
+        value: B::Owned,
+    ) -> Cow<'a, B> {
+        Cow::Owned(value)
+    }
+
+    /// Returns the [`String`] produced by formatting `args`.
+    ///
+    /// `format_args!` can be passed directly; the
+    /// [`ret_format_args!`](crate::ret_format_args) macro is shorthand
+    /// for exactly that.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unit_ext::*;
+    ///
+    /// let (user, id) = ("ferris", 7);
+    /// let msg = println!("formatting")
+    ///     .ret_format(format_args!("{user}#{id}"));
+    /// assert_eq!(msg, "ferris#7");
+    ///
+    /// let quoted = ().ret_format(format_args!("{:?} → {}", "a\"b", 'ö'));
+    /// assert_eq!(quoted, "\"a\\\"b\" → ö");
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    #[inline]
+    fn ret_format(self, args: fmt::Arguments<'_>) -> String {
+        alloc::fmt::format(args)
+    }
+
+    /// Returns `value` formatted with its [`Debug`](fmt::Debug) impl,
+    /// as `format!("{value:?}")` would.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unit_ext::*;
+    ///
+    /// let shown = println!("converting").ret_debug_str(&Some("héllo"));
+    /// assert_eq!(shown, r#"Some("héllo")"#);
+    /// assert_eq!(().ret_debug_str(&None::<u8>), "None");
+    /// assert_eq!(().ret_debug_str("tab\t"), r#""tab\t""#);
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    #[inline]
+    fn ret_debug_str<T: fmt::Debug + ?Sized>(
+        self,
+        value: &T,
+    ) -> String {
+        self.ret_format(format_args!("{value:?}"))
+    }
+
+    /// Returns `value` formatted with its [`Display`](fmt::Display)
+    /// impl, as `format!("{value}")` would.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unit_ext::*;
+    ///
+    /// let shown = println!("converting").ret_display_str("héllo");
+    /// assert_eq!(shown, "héllo");
+    /// assert_ne!(shown, ().ret_debug_str("héllo"));
+    /// assert_eq!(().ret_display_str(&1.5), "1.5");
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    #[inline]
+    fn ret_display_str<T: fmt::Display + ?Sized>(
+        self,
+        value: &T,
+    ) -> String {
+        self.ret_format(format_args!("{value}"))
+    }
+
+    /// Returns an empty [`BTreeMap`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unit_ext::*;
+    ///
+    /// let mut index = println!("new index").ret_btree_map();
+    /// index.insert("b", 2);
+    /// index.insert("a", 1);
+    /// assert_eq!(index.keys().collect::<Vec<_>>(), [&"a", &"b"]);
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    #[inline]
+    fn ret_btree_map<K, V>(self) -> BTreeMap<K, V> {
+        BTreeMap::new()
+    }
+
+    /// Returns an empty [`BTreeSet`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unit_ext::*;
+    ///
+    /// let mut seen = ().ret_btree_set();
+    /// assert!(seen.insert(3));
+    /// assert!(!seen.insert(3));
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    #[inline]
+    fn ret_btree_set<V>(self) -> BTreeSet<V> {
+        BTreeSet::new()
+    }
+
+    /// Returns an empty [`VecDeque`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unit_ext::*;
+    ///
+    /// let mut queue = println!("queue ready").ret_deque();
+    /// queue.push_back(2);
+    /// queue.push_front(1);
+    /// assert_eq!(queue.pop_front(), Some(1));
+    /// assert_eq!(queue.pop_back(), Some(2));
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    #[inline]
+    fn ret_deque<T>(self) -> VecDeque<T> {
+        VecDeque::new()
+    }
+
+    /// Returns `VecDeque::with_capacity(capacity)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unit_ext::*;
+    ///
+    /// let queue = ().ret_deque_with_capacity::<u8>(10);
+    /// assert!(queue.is_empty());
+    /// assert!(queue.capacity() >= 10);
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    #[inline]
+    fn ret_deque_with_capacity<T>(
+        self,
+        capacity: usize,
+    ) -> VecDeque<T> {
+        VecDeque::with_capacity(capacity)
+    }
+
+    /// Returns a [`VecDeque`] holding a single `T::default()`.
+    ///
+    /// Unlike `ret_default::<VecDeque<T>>()`, the deque is not empty;
+    /// this seeds queue-based algorithms with a starting element.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unit_ext::*;
+    /// use std::collections::VecDeque;
+    ///
+    /// let mut frontier: VecDeque<u32> = ().ret_deque_default();
+    /// assert_eq!(frontier, [0]);
+    /// frontier.push_back(1);
+    /// assert_eq!(frontier.pop_front(), Some(0));
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    #[inline]
+    fn ret_deque_default<T: Default>(self) -> VecDeque<T> {
+        VecDeque::from([T::default()])
+    }
+
+    /// Returns `Rc::new(value)`.
+    ///
+    /// `Rc` is not `Send`; use [`ret_arc`](UnitExt::ret_arc) when the
+    /// value has to cross threads:
+    ///
+    /// ```compile_fail
+    /// use unit_ext::*;
+    /// fn assert_send<T: Send>(_: T) {}
+    /// assert_send(().ret_rc(1));
+    /// ```
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unit_ext::*;
+    /// use std::rc::Rc;
+    ///
+    /// let a = println!("sharing").ret_rc(5);
+    /// let b = Rc::clone(&a);
+    /// assert_eq!(*b, 5);
+    /// assert_eq!(Rc::strong_count(&a), 2);
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    #[inline]
+    fn ret_rc<T>(self, value: T) -> Rc<T> {
+        Rc::new(value)
+    }
+
+    /// Returns `Rc::new(T::default())`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unit_ext::*;
+    /// use std::rc::Rc;
+    ///
+    /// let rc: Rc<String> = ().ret_rc_default();
+    /// assert!(rc.is_empty());
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    #[inline]
+    fn ret_rc_default<T: Default>(self) -> Rc<T> {
+        self.ret_rc(T::default())
+    }
+
+    /// Returns `String::from(s)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unit_ext::*;
+    ///
+    /// let name = println!("fallback name").ret_string("anonymous");
+    /// assert_eq!(name, "anonymous");
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    #[inline]
+    fn ret_string(self, s: &str) -> String {
+        String::from(s)
+    }
+
+    /// Returns `String::with_capacity(capacity)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unit_ext::*;
+    ///
+    /// let buf = ().ret_string_with_capacity(64);
+    /// assert!(buf.is_empty());
+    /// assert!(buf.capacity() >= 64);
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    #[inline]
+    fn ret_string_with_capacity(self, capacity: usize) -> String {
+        String::with_capacity(capacity)
+    }
+
+    /// Returns an empty [`String`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unit_ext::*;
+    ///
+    /// let s = ().ret_string_default();
+    /// assert_eq!(s, "");
+    /// assert_eq!(s.capacity(), 0);
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    #[inline]
+    fn ret_string_default(self) -> String {
+        String::new()
+    }
+
+    /// Returns [`String::from_utf8_lossy(bytes)`](String::from_utf8_lossy).
+    ///
+    /// Valid UTF-8 is borrowed as is; otherwise every invalid sequence
+    /// is replaced with `U+FFFD REPLACEMENT CHARACTER` in a new
+    /// `String`. The result borrows from `bytes`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unit_ext::*;
+    /// use std::borrow::Cow;
+    ///
+    /// let text = println!("got packet").ret_lossy_str(b"ping");
+    /// assert!(matches!(text, Cow::Borrowed("ping")));
+    ///
+    /// let broken = ().ret_lossy_str(b"ab\xFFcd");
+    /// assert_eq!(broken, "ab\u{FFFD}cd");
+    /// assert_eq!(().ret_lossy_str(b""), "");
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    #[inline]
+    fn ret_lossy_str(self, bytes: &[u8]) -> Cow<'_, str> {
+        String::from_utf8_lossy(bytes)
+    }
+
+    /// Returns `Some(String::new())`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unit_ext::*;
+    ///
+    /// let label = |named: bool| {
+    ///     if !named {
+    ///         return ().ret_none();
+    ///     }
+    ///     println!("fresh label").ret_some_string()
+    /// };
+    /// assert_eq!(label(true), Some(String::new()));
+    /// assert_eq!(label(false), None);
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    #[inline]
+    fn ret_some_string(self) -> Option<String> {
+        Some(String::new())
+    }
+
+    /// Returns `Ok(String::new())`.
+    ///
+    /// The error type is left to the caller.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unit_ext::*;
+    ///
+    /// fn read_name(raw: &[u8]) -> Result<String, std::str::Utf8Error> {
+    ///     if raw.is_empty() {
+    ///         return ().ret_ok_string();
+    ///     }
+    ///     let name = std::str::from_utf8(raw)?;
+    ///     Ok(name.to_owned())
+    /// }
+    ///
+    /// assert_eq!(read_name(b""), Ok(String::new()));
+    /// assert_eq!(read_name(b"ferris").as_deref(), Ok("ferris"));
+    /// assert!(read_name(b"\xFF").is_err());
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    #[inline]
+    fn ret_ok_string<E>(self) -> Result<String, E> {
+        Ok(String::new())
+    }
+
+    /// Returns an empty [`Vec`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unit_ext::*;
+    ///
+    /// let rows = println!("no rows").ret_vec::<u8>();
+    /// assert!(rows.is_empty());
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    #[inline]
+    fn ret_vec<T>(self) -> Vec<T> {
+        Vec::new()
+    }
+
+    /// Returns `Vec::with_capacity(capacity)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unit_ext::*;
+    ///
+    /// let v = ().ret_vec_with_capacity::<u64>(16);
+    /// assert_eq!(v.len(), 0);
+    /// assert!(v.capacity() >= 16);
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    #[inline]
+    fn ret_vec_with_capacity<T>(self, capacity: usize) -> Vec<T> {
+        Vec::with_capacity(capacity)
+    }
+
+    /// Returns `vec![value]`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unit_ext::*;
+    ///
+    /// let v = println!("single item").ret_vec_of("only");
+    /// assert_eq!(v.len(), 1);
+    /// assert_eq!(v, ["only"]);
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    #[inline]
+    fn ret_vec_of<T>(self, value: T) -> Vec<T> {
+        alloc::vec![value]
+    }
+
+    /// Returns `Some(Vec::new())`.
+    ///
+    /// Reads better than `ret_some_default::<Vec<T>>()`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unit_ext::*;
+    ///
+    /// fn buffer(enabled: bool) -> Option<Vec<u8>> {
+    ///     if enabled { ().ret_some_vec() } else { ().ret_none() }
+    /// }
+    ///
+    /// let mut buf = buffer(true).unwrap();
+    /// assert!(buf.is_empty());
+    /// buf.push(1);
+    /// assert_eq!(buffer(false), None);
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    #[inline]
+    fn ret_some_vec<T>(self) -> Option<Vec<T>> {
+        Some(Vec::new())
+    }
+
+    /// Returns `Ok(Vec::new())`.
+    ///
+    /// The usual answer of a parser handed empty input. The error type
+    /// is left to the caller.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unit_ext::*;
+    /// use std::num::ParseIntError;
+    ///
+    /// fn numbers(line: &str) -> Result<Vec<u32>, ParseIntError> {
+    ///     if line.is_empty() {
+    ///         return println!("empty line").ret_ok_vec();
+    ///     }
+    ///     line.split(',').map(str::parse).collect()
+    /// }
+    ///
+    /// fn total(line: &str) -> Result<u32, ParseIntError> {
+    ///     Ok(numbers(line)?.into_iter().sum())
+    /// }
+    ///
+    /// assert_eq!(numbers(""), Ok(vec![]));
+    /// assert_eq!(total("1,2"), Ok(3));
+    /// assert!(total("1,x").is_err());
+    ///
+    /// let any_error: Result<Vec<u8>, ()> = ().ret_ok_vec();
+    /// assert_eq!(any_error, Ok(vec![]));
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    #[inline]
+    fn ret_ok_vec<T, E>(self) -> Result<Vec<T>, E> {
+        Ok(Vec::new())
+    }
+
+    /// Returns a sorted copy of `slice`, leaving the original as is.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unit_ext::*;
+    ///
+    /// let input = [3, 1, 2];
+    /// let sorted = println!("sorting").ret_sort_cloned(&input);
+    /// assert_eq!(sorted, [1, 2, 3]);
+    /// assert_eq!(input, [3, 1, 2]);
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    #[inline]
+    fn ret_sort_cloned<T: Ord + Clone>(self, slice: &[T]) -> Vec<T> {
+        let mut sorted = slice.to_vec();
+        sorted.sort();
+        sorted
+    }
+
+    /// Returns a copy of `slice` sorted with `cmp`, leaving the
+    /// original as is.
+    ///
+    /// The sort is stable: elements that compare equal keep their
+    /// original order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unit_ext::*;
+    ///
+    /// let input = [("b", 2), ("a", 1), ("c", 2), ("d", 1)];
+    /// let by_rank = ().ret_sort_by_cloned(&input, |x, y| y.1.cmp(&x.1));
+    /// assert_eq!(by_rank, [("b", 2), ("c", 2), ("a", 1), ("d", 1)]);
+    /// assert_eq!(input[0], ("b", 2));
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    #[inline]
+    fn ret_sort_by_cloned<T: Clone, F>(
+        self,
+        slice: &[T],
+        cmp: F,
+    ) -> Vec<T>
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        let mut sorted = slice.to_vec();
+        sorted.sort_by(cmp);
+        sorted
+    }
+
+    /// Returns `Arc::new(value)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unit_ext::*;
+    /// use std::sync::Arc;
+    /// use std::thread;
+    ///
+    /// let shared = println!("init done").ret_arc(vec![1, 2, 3]);
+    /// let worker = {
+    ///     let shared = Arc::clone(&shared);
+    ///     thread::spawn(move || shared.iter().sum::<i32>())
+    /// };
+    /// assert_eq!(worker.join().unwrap(), 6);
+    /// ```
+    #[cfg(feature = "std")]
+    #[must_use]
+    #[inline]
+    fn ret_arc<T>(self, value: T) -> Arc<T> {
+        Arc::new(value)
+    }
+
+    /// Returns `Arc::new(T::default())`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unit_ext::*;
+    /// use std::sync::Arc;
+    ///
+    /// let arc: Arc<u32> = ().ret_arc_default();
+    /// assert_eq!(*arc, 0);
+    /// ```
+    #[cfg(feature = "std")]
+    #[must_use]
+    #[inline]
+    fn ret_arc_default<T: Default>(self) -> Arc<T> {
+        self.ret_arc(T::default())
+    }
+
+    /// Returns an empty [`HashMap`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unit_ext::*;
+    ///
+    /// let mut cache = println!("cache created").ret_hash_map();
+    /// cache.insert("k", 1);
+    /// assert_eq!(cache.get("k"), Some(&1));
+    /// ```
+    #[cfg(feature = "std")]
+    #[must_use]
+    #[inline]
+    fn ret_hash_map<K, V>(self) -> HashMap<K, V> {
+        HashMap::new()
+    }
+
+    /// Returns `HashMap::with_capacity(capacity)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unit_ext::*;
+    /// use std::collections::HashMap;
+    ///
+    /// let map: HashMap<u8, u8> = ().ret_hash_map_with_capacity(32);
+    /// assert!(map.is_empty());
+    /// assert!(map.capacity() >= 32);
+    /// ```
+    #[cfg(feature = "std")]
+    #[must_use]
+    #[inline]
+    fn ret_hash_map_with_capacity<K, V>(
+        self,
+        capacity: usize,
+    ) -> HashMap<K, V> {
+        HashMap::with_capacity(capacity)
+    }
+
+    /// Returns an empty [`HashSet`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unit_ext::*;
+    ///
+    /// let mut seen = println!("dedup").ret_hash_set();
+    /// assert!(seen.insert("a"));
+    /// assert!(!seen.insert("a"));
+    /// ```
+    #[cfg(feature = "std")]
+    #[must_use]
+    #[inline]
+    fn ret_hash_set<V>(self) -> HashSet<V> {
+        HashSet::new()
+    }
+
+    /// Returns `HashSet::with_capacity(capacity)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unit_ext::*;
+    /// use std::collections::HashSet;
+    ///
+    /// let set: HashSet<u8> = ().ret_hash_set_with_capacity(8);
+    /// assert!(set.capacity() >= 8);
+    /// ```
+    #[cfg(feature = "std")]
+    #[must_use]
+    #[inline]
+    fn ret_hash_set_with_capacity<V>(
+        self,
+        capacity: usize,
+    ) -> HashSet<V> {
+        HashSet::with_capacity(capacity)
+    }
+
+    /// Returns `Some(HashMap::new())`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unit_ext::*;
+    /// use std::collections::HashMap;
+    ///
+    /// fn index(words: &[&'static str]) -> Option<HashMap<&'static str, usize>> {
+    ///     let mut map = println!("indexing").ret_some_map()?;
+    ///     for (i, w) in words.iter().enumerate() {
+    ///         map.insert(*w, i);
+    ///     }
+    ///     Some(map)
+    /// }
+    ///
+    /// assert_eq!(index(&[]).map(|m| m.len()), Some(0));
+    /// assert_eq!(index(&["a"]).unwrap()["a"], 0);
+    /// ```
+    #[cfg(feature = "std")]
+    #[must_use]
+    #[inline]
+    fn ret_some_map<K, V>(self) -> Option<HashMap<K, V>> {
+        Some(HashMap::new())
+    }
+
+    /// Returns `Ok(HashMap::new())`.
+    ///
+    /// The error type is left to the caller.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unit_ext::*;
+    /// use std::collections::HashMap;
+    ///
+    /// fn parse(src: &str) -> Result<HashMap<&str, &str>, String> {
+    ///     let mut map = ().ret_ok_map::<_, _, String>()?;
+    ///     for line in src.lines() {
+    ///         let (k, v) = line.split_once('=').ok_or(format!("bad line: {line}"))?;
+    ///         map.insert(k, v);
+    ///     }
+    ///     Ok(map)
+    /// }
+    ///
+    /// assert!(parse("").unwrap().is_empty());
+    /// assert_eq!(parse("a=1").unwrap()["a"], "1");
+    /// assert!(parse("oops").is_err());
+    /// ```
+    #[cfg(feature = "std")]
+    #[must_use]
+    #[inline]
+    fn ret_ok_map<K, V, E>(self) -> Result<HashMap<K, V>, E> {
+        Ok(HashMap::new())
+    }
+
+    /// Returns `Mutex::new(value)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unit_ext::*;
+    ///
+    /// let state = println!("init complete").ret_mutex(vec![1]);
+    /// state.lock().unwrap().push(2);
+    /// assert_eq!(*state.lock().unwrap(), [1, 2]);
+    /// ```
+    #[cfg(feature = "std")]
+    #[must_use]
+    #[inline]
+    fn ret_mutex<T>(self, value: T) -> Mutex<T> {
+        Mutex::new(value)
+    }
+
+    /// Returns `Mutex::new(T::default())`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unit_ext::*;
+    /// use std::sync::Mutex;
+    ///
+    /// let count: Mutex<u32> = ().ret_mutex_default();
+    /// *count.lock().unwrap() += 1;
+    /// assert_eq!(*count.lock().unwrap(), 1);
+    /// ```
+    #[cfg(feature = "std")]
+    #[must_use]
+    #[inline]
+    fn ret_mutex_default<T: Default>(self) -> Mutex<T> {
+        self.ret_mutex(T::default())
+    }
+
+    /// Returns `RwLock::new(value)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unit_ext::*;
+    ///
+    /// let config = println!("config loaded").ret_rw_lock("debug");
+    /// assert_eq!(*config.read().unwrap(), "debug");
+    /// *config.write().unwrap() = "release";
+    /// assert_eq!(*config.read().unwrap(), "release");
+    /// ```
+    #[cfg(feature = "std")]
+    #[must_use]
+    #[inline]
+    fn ret_rw_lock<T>(self, value: T) -> RwLock<T> {
+        RwLock::new(value)
+    }
+
+    /// Returns `RwLock::new(T::default())`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unit_ext::*;
+    /// use std::sync::RwLock;
+    ///
+    /// let lock: RwLock<Vec<u8>> = ().ret_rw_lock_default();
+    /// assert!(lock.read().unwrap().is_empty());
+    /// ```
+    #[cfg(feature = "std")]
+    #[must_use]
+    #[inline]
+    fn ret_rw_lock_default<T: Default>(self) -> RwLock<T> {
+        self.ret_rw_lock(T::default())
+    }
+
+    /// Returns `Arc::new(Mutex::new(value))`, the usual shape of
+    /// state shared between threads.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unit_ext::*;
+    /// use std::{sync::Arc, thread};
+    ///
+    /// let hits = ().ret_arc_mutex(0u32);
+    /// let workers: Vec<_> = (0..4)
+    ///     .map(|_| {
+    ///         let hits = Arc::clone(&hits);
+    ///         thread::spawn(move || *hits.lock().unwrap() += 1)
+    ///     })
+    ///     .collect();
+    /// workers.into_iter().for_each(|w| w.join().unwrap());
+    /// assert_eq!(*hits.lock().unwrap(), 4);
+    /// ```
+    #[cfg(feature = "std")]
+    #[must_use]
+    #[inline]
+    fn ret_arc_mutex<T>(self, value: T) -> Arc<Mutex<T>> {
+        self.ret_arc(Mutex::new(value))
+    }
+
+    /// Returns `Arc::new(Mutex::new(T::default()))`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unit_ext::*;
+    /// use std::sync::{Arc, Mutex};
+    ///
+    /// let log: Arc<Mutex<Vec<&str>>> = ().ret_arc_mutex_default();
+    /// std::thread::scope(|s| {
+    ///     s.spawn(|| log.lock().unwrap().push("worker"));
+    /// });
+    /// assert_eq!(*log.lock().unwrap(), ["worker"]);
+    /// ```
+    #[cfg(feature = "std")]
+    #[must_use]
+    #[inline]
+    fn ret_arc_mutex_default<T: Default>(self) -> Arc<Mutex<T>> {
+        self.ret_arc_mutex(T::default())
+    }
+
+    /// Returns `Arc::new(RwLock::new(value))`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unit_ext::*;
+    /// use std::{sync::Arc, thread};
+    ///
+    /// let config = ().ret_arc_rw_lock("debug");
+    /// let reader = {
+    ///     let config = Arc::clone(&config);
+    ///     thread::spawn(move || *config.read().unwrap())
+    /// };
+    /// assert_eq!(reader.join().unwrap(), "debug");
+    /// *config.write().unwrap() = "release";
+    /// assert_eq!(*config.read().unwrap(), "release");
+    /// ```
+    #[cfg(feature = "std")]
+    #[must_use]
+    #[inline]
+    fn ret_arc_rw_lock<T>(self, value: T) -> Arc<RwLock<T>> {
+        self.ret_arc(RwLock::new(value))
+    }
+
+    /// Returns `Arc::new(RwLock::new(T::default()))`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unit_ext::*;
+    /// use std::sync::{Arc, RwLock};
+    ///
+    /// let seen: Arc<RwLock<u64>> = ().ret_arc_rw_lock_default();
+    /// std::thread::scope(|s| {
+    ///     s.spawn(|| *seen.write().unwrap() = 7);
+    /// });
+    /// assert_eq!(*seen.read().unwrap(), 7);
+    /// ```
+    #[cfg(feature = "std")]
+    #[must_use]
+    #[inline]
+    fn ret_arc_rw_lock_default<T: Default>(self) -> Arc<RwLock<T>> {
+        self.ret_arc_rw_lock(T::default())
+    }
+
+    /// Returns `io::Cursor::new(value)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unit_ext::*;
+    /// use std::io::Write;
+    ///
+    /// let mut out = println!("buffer ready").ret_io_cursor(vec![0; 4]);
+    /// out.write_all(b"ok").unwrap();
+    /// assert_eq!(out.position(), 2);
+    /// assert_eq!(out.into_inner(), [b'o', b'k', 0, 0]);
+    /// ```
+    #[cfg(feature = "std")]
+    #[must_use]
+    #[inline]
+    fn ret_io_cursor<T>(self, value: T) -> io::Cursor<T> {
+        io::Cursor::new(value)
+    }
+
+    /// Returns an empty `io::Cursor<Vec<u8>>`, the usual in-memory
+    /// [`Write`](io::Write) target in tests.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unit_ext::*;
+    /// use std::io::Write;
+    ///
+    /// let mut out = ().ret_io_cursor_empty();
+    /// write!(out, "{}-{}", 1, 2).unwrap();
+    /// assert_eq!(out.get_ref(), b"1-2");
+    /// ```
+    #[cfg(feature = "std")]
+    #[must_use]
+    #[inline]
+    fn ret_io_cursor_empty(self) -> io::Cursor<Vec<u8>> {
+        self.ret_io_cursor(Vec::new())
+    }
+
+    /// Returns [`Instant::now()`](Instant::now).
+    ///
+    /// Captures the time right after the unit expression ran, which is
+    /// handy for timing a block that starts with a log line.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unit_ext::*;
+    ///
+    /// let start = println!("starting").ret_instant_now();
+    /// let later = ().ret_instant_now();
+    /// assert!(later >= start);
+    /// assert!(later.duration_since(start) >= std::time::Duration::ZERO);
+    /// ```
+    #[cfg(feature = "std")]
+    #[must_use]
+    #[inline]
+    fn ret_instant_now(self) -> Instant {
+        Instant::now()
+    }
+
+    /// Returns [`SystemTime::now()`](SystemTime::now).
+    ///
+    /// Unlike [`Instant`], the system clock is not monotonic, so use
+    /// this for timestamps rather than measuring elapsed time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unit_ext::*;
+    /// use std::time::UNIX_EPOCH;
+    ///
+    /// let stamp = println!("event").ret_system_time_now();
+    /// assert!(stamp.duration_since(UNIX_EPOCH).is_ok());
+    /// ```
+    #[cfg(feature = "std")]
+    #[must_use]
+    #[inline]
+    fn ret_system_time_now(self) -> SystemTime {
+        SystemTime::now()
+    }
+
+    /// Returns `path` as an owned [`PathBuf`].
+    ///
+    /// Accepts anything that is [`AsRef<Path>`], so string slices,
+    /// `String`s and existing `PathBuf`s all work.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unit_ext::*;
+    /// use std::path::{Path, PathBuf};
+    ///
+    /// let base = PathBuf::from("target");
+    /// let out = println!("validated").ret_path_buf(base.join("output"));
+    /// assert_eq!(out, Path::new("target/output"));
+    /// assert_eq!(().ret_path_buf("a/b"), PathBuf::from("a/b"));
+    /// ```
+    #[cfg(feature = "std")]
+    #[must_use]
+    #[inline]
+    fn ret_path_buf(self, path: impl AsRef<Path>) -> PathBuf {
+        path.as_ref().to_path_buf()
+    }
+
+    /// Returns an empty [`PathBuf`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unit_ext::*;
+    ///
+    /// let mut path = ().ret_path_buf_default();
+    /// assert_eq!(path.as_os_str(), "");
+    /// path.push("logs");
+    /// assert_eq!(path.to_str(), Some("logs"));
+    /// ```
+    #[cfg(feature = "std")]
+    #[must_use]
+    #[inline]
+    fn ret_path_buf_default(self) -> PathBuf {
+        PathBuf::new()
+    }
+
+    /// Returns `path` borrowed as a [`Path`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unit_ext::*;
+    ///
+    /// let name = String::from("notes.txt");
+    /// let path = println!("opening").ret_path(&name);
+    /// assert_eq!(path.extension().unwrap(), "txt");
+    /// ```
+    #[cfg(feature = "std")]
+    #[must_use]
+    #[inline]
+    fn ret_path<P: AsRef<Path> + ?Sized>(self, path: &P) -> &Path {
+        path.as_ref()
+    }
+
+    /// Returns [`env::var(key)`](env::var).
+    ///
+    /// # Errors
+    ///
+    /// Fails with [`env::VarError::NotPresent`] if `key` is unset and
+    /// with [`env::VarError::NotUnicode`] if its value is not valid
+    /// Unicode.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unit_ext::*;
+    /// use std::env::{self, VarError};
+    ///
+    /// // SAFETY: the example runs in its own process and spawns no
+    /// // other threads that could read the environment concurrently.
+    /// unsafe { env::set_var("UNIT_EXT_DB_URL", "sqlite::memory:") };
+    ///
+    /// let url = println!("loading config").ret_env_var("UNIT_EXT_DB_URL");
+    /// assert_eq!(url.as_deref(), Ok("sqlite::memory:"));
+    /// assert_eq!(
+    ///     ().ret_env_var("UNIT_EXT_SURELY_UNSET"),
+    ///     Err(VarError::NotPresent),
+    /// );
+    /// ```
+    #[cfg(feature = "std")]
+    #[must_use]
+    #[inline]
+    fn ret_env_var(self, key: &str) -> Result<String, env::VarError> {
+        env::var(key)
+    }
+
+    /// Logs `msg` at `level`, then returns `Ok(value)`.
+    ///
+    /// Requires the `log` feature.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unit_ext::*;
+    /// use log::Level;
+    ///
+    /// let saved: Result<u32, String> = ().ret_ok_and_log(7, Level::Info, "row saved");
+    /// assert_eq!(saved, Ok(7));
+    /// ```
+    #[cfg(feature = "log")]
+    #[must_use]
+    #[inline]
+    fn ret_ok_and_log<T, E>(
+        self,
+        value: T,
+        level: log::Level,
+        msg: &str,
+    ) -> Result<T, E> {
+        log::log!(level, "{msg}");
+        Ok(value)
+    }
+
+    /// Logs `"{msg}: {error:?}"` at `level`, then returns
+    /// `Err(error)`.
+    ///
+    /// Requires the `log` feature.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unit_ext::*;
+    /// use log::Level;
+    ///
+    /// let failed: Result<u32, _> = ().ret_err_and_log("timeout", Level::Error, "query failed");
+    /// assert_eq!(failed, Err("timeout"));
+    /// ```
+    #[cfg(feature = "log")]
+    #[must_use]
+    #[inline]
+    fn ret_err_and_log<T, E: fmt::Debug>(
+        self,
+        error: E,
+        level: log::Level,
+        msg: &str,
+    ) -> Result<T, E> {
+        log::log!(level, "{msg}: {error:?}");
+        Err(error)
+    }
+}
+
+/// Extension methods for any value that explicitly discard the value
+/// and yield unit `()`.
+///
+/// The helpers make intent obvious when chaining iterator or async
+/// pipelines.
+///
+/// # Examples
+///
+/// ```
+/// use unit_ext::*;
+///
+/// (0..3).for_each(|n| n.into_unit());
+/// let x: Option<usize> = Some(0).into_unit().ret_some(1);
+/// assert_eq!("scratch".discard_and_ret(x), Some(1));
+/// ```
+pub trait RetExt: Sized {
+    /// Discards `self`, returning `()`.
+    ///
+    /// Equivalent to `let _ = self;`. Kept alongside
+    /// [`IntoUnit::into_unit`], which is the preferred name.
+    #[inline]
+    fn discard_self(self) {
+        let _ = self;
+    }
+
+    /// Alias for [`discard_self`](RetExt::discard_self)
+    #[inline]
+    fn discard_ret(self) {
+        self.discard_self();
+    }
+
+    /// Discards `self` and returns `value` instead.
+    ///
+    /// `self` is dropped before the call returns, so by the time the
+    /// caller sees `value` the discarded one is already gone.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unit_ext::*;
+    ///
+    /// assert_eq!("scratch".discard_and_ret(42), 42);
+    /// ```
+    #[must_use]
+    #[inline]
+    fn discard_and_ret<T>(self, value: T) -> T {
+        self.discard_self();
+        value
+    }
+
+    /// Discards `self`, then calls `f`.
+    ///
+    /// An explicit spelling of `{ a(); b(); }` as `a().chain_discard(b)`.
+    /// `self` is dropped before `f` runs.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unit_ext::*;
+    /// use std::cell::RefCell;
+    ///
+    /// struct Guard<'a>(&'a RefCell<Vec<&'static str>>);
+    /// impl Drop for Guard<'_> {
+    ///     fn drop(&mut self) {
+    ///         self.0.borrow_mut().push("dropped");
+    ///     }
+    /// }
+    ///
+    /// let log = RefCell::new(Vec::new());
+    /// Guard(&log).chain_discard(|| log.borrow_mut().push("next"));
+    /// assert_eq!(*log.borrow(), ["dropped", "next"]);
+    /// ```
+    #[inline]
+    fn chain_discard<F: FnOnce()>(self, f: F) {
+        self.discard_self();
+        f();
+    }
+
+    /// Discards `self`, then returns `f()`.
+    ///
+    /// The lazy form of [`discard_and_ret`](RetExt::discard_and_ret):
+    /// `self` is dropped before `f` runs.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unit_ext::*;
+    /// use std::cell::Cell;
+    ///
+    /// let steps = Cell::new(0);
+    /// let step = || steps.replace(steps.get() + 1);
+    ///
+    /// let order = step().chain_discard_ret(step);
+    /// assert_eq!(order, 1);
+    /// assert_eq!(steps.get(), 2);
+    /// ```
+    #[must_use]
+    #[inline]
+    fn chain_discard_ret<T, F: FnOnce() -> T>(self, f: F) -> T {
+        self.discard_self();
+        f()
+    }
+
+    /// Returns `Some(self)` if `f` returns `true`, otherwise drops
+    /// `self` and returns [`None`].
+    ///
+    /// The value-first counterpart of [`Option::filter`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unit_ext::*;
+    ///
+    /// assert_eq!(5.discard_unless(|n| *n > 3), Some(5));
+    /// assert_eq!(1.discard_unless(|n| *n > 3), None);
+    /// ```
+    #[must_use]
+    #[inline]
+    fn discard_unless<F: FnOnce(&Self) -> bool>(
+        self,
+        f: F,
+    ) -> Option<Self> {
+        if f(&self) { Some(self) } else { None }
+    }
+
+    /// Returns [`None`], dropping `self`, if `f` returns `true`,
+    /// otherwise `Some(self)`.
+    ///
+    /// The inverse of [`discard_unless`](RetExt::discard_unless).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unit_ext::*;
+    ///
+    /// assert_eq!("".discard_if(|s| s.is_empty()), None);
+    /// assert_eq!("a".discard_if(|s| s.is_empty()), Some("a"));
+    /// ```
+    #[must_use]
+    #[inline]
+    fn discard_if<F: FnOnce(&Self) -> bool>(
+        self,
+        f: F,
+    ) -> Option<Self> {
+        self.discard_unless(|v| !f(v))
+    }
+
+    /// Returns `Ok(self)` if `predicate` returns `true`, otherwise
+    /// drops `self` and returns `Err(err)`.
+    ///
+    /// The `Result` counterpart of
+    /// [`discard_unless`](RetExt::discard_unless). `predicate` only
+    /// borrows the value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unit_ext::*;
+    ///
+    /// assert_eq!(7.ok_if(|n| *n > 5, "too small"), Ok(7));
+    /// assert_eq!(1.ok_if(|n| *n > 5, "too small"), Err("too small"));
+    /// ```
+    #[must_use]
+    #[inline]
+    fn ok_if<E, F: FnOnce(&Self) -> bool>(
+        self,
+        predicate: F,
+        err: E,
+    ) -> Result<Self, E> {
+        self.ok_if_else(predicate, || err)
+    }
+
+    /// Returns `Ok(self)` if `predicate` returns `true`, otherwise
+    /// drops `self` and returns `Err(err_fn())`.
+    ///
+    /// The lazy form of [`ok_if`](RetExt::ok_if); `err_fn` only runs
+    /// when the predicate fails.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unit_ext::*;
+    ///
+    /// let port = |p: u16| p.ok_if_else(|p| *p > 1024, || format!("port {p} is privileged"));
+    ///
+    /// assert_eq!(port(8080), Ok(8080));
+    /// assert_eq!(port(80).unwrap_err(), "port 80 is privileged");
+    /// ```
+    #[must_use]
+    #[inline]
+    fn ok_if_else<E, F, G>(
+        self,
+        predicate: F,
+        err_fn: G,
+    ) -> Result<Self, E>
+    where
+        F: FnOnce(&Self) -> bool,
+        G: FnOnce() -> E,
+    {
+        if predicate(&self) {
+            Ok(self)
+        } else {
+            Err(err_fn())
+        }
+    }
+
+    /// Wraps `self` in [`Some`].
+    ///
+    /// The value-first counterpart of [`UnitExt::ret_some`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unit_ext::*;
+    ///
+    /// let opt = (2 + 3).into_some();
+    /// assert_eq!(opt, Some(5));
+    ///
+    /// let opt = None.or_else(|| println!("fallback").ret(7).into_some());
+    /// assert_eq!(opt, Some(7));
+    /// ```
+    #[must_use]
+    #[inline]
+    fn into_some(self) -> Option<Self> {
+        Some(self)
+    }
+
+    /// Wraps `self` in [`Ok`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unit_ext::*;
+    ///
+    /// let res = "done".into_ok::<()>();
+    /// assert_eq!(res, Ok("done"));
+    ///
+    /// let res: Result<u8, &str> = Err("first")
+    ///     .or_else(|e| eprintln!("{e}").ret(0).into_ok());
+    /// assert_eq!(res, Ok(0));
+    /// ```
+    #[must_use]
+    #[inline]
+    fn into_ok<E>(self) -> Result<Self, E> {
+        Ok(self)
+    }
+
+    /// Wraps `self` in [`Err`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unit_ext::*;
+    ///
+    /// let res = "boom".into_err::<u8>();
+    /// assert_eq!(res, Err("boom"));
+    ///
+    /// let res = "x".parse::<u8>().map_or_else(
+    ///     |e| eprintln!("{e}").ret("invalid").into_err(),
+    ///     |v| v.into_ok(),
+    /// );
+    /// assert_eq!(res, Err("invalid"));
+    /// ```
+    #[must_use]
+    #[inline]
+    fn into_err<T>(self) -> Result<T, Self> {
+        Err(self)
+    }
+
+    /// Wraps `self` in `Poll::Ready`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unit_ext::*;
+    /// use std::task::Poll;
+    ///
+    /// let p = (2 * 21).into_poll_ready();
+    /// assert_eq!(p, Poll::Ready(42));
+    /// assert_eq!(p.map(|n| n + 1), Poll::Ready(42).map(|n| n + 1));
+    /// ```
+    #[must_use]
+    #[inline]
+    fn into_poll_ready(self) -> Poll<Self> {
+        Poll::Ready(self)
+    }
+
+    /// Wraps `self` in `ControlFlow::Break`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unit_ext::*;
+    /// use std::ops::ControlFlow;
+    ///
+    /// let found = [3, 8, 5].iter().try_for_each(|&n| {
+    ///     if n > 4 {
+    ///         n.into_control_flow_break()
+    ///     } else {
+    ///         ().into_control_flow_continue()
+    ///     }
+    /// });
+    /// assert_eq!(found, ControlFlow::Break(8));
+    /// ```
+    #[must_use]
+    #[inline]
+    fn into_control_flow_break<C>(self) -> ControlFlow<Self, C> {
+        ControlFlow::Break(self)
+    }
+
+    /// Wraps `self` in `ControlFlow::Continue`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unit_ext::*;
+    /// use std::ops::ControlFlow;
+    ///
+    /// let total = [1, 2, 3].iter().try_fold(0, |acc, &n| {
+    ///     if acc > 10 {
+    ///         ControlFlow::Break(acc)
+    ///     } else {
+    ///         (acc + n).into_control_flow_continue()
+    ///     }
+    /// });
+    /// assert_eq!(total, ControlFlow::Continue(6));
+    /// ```
+    #[must_use]
+    #[inline]
+    fn into_control_flow_continue<B>(self) -> ControlFlow<B, Self> {
+        ControlFlow::Continue(self)
+    }
+
+    /// Calls `f` with a reference to `self`, then returns `self`.
+    ///
+    /// Reads as "do this as well, then carry on with the same value".
+    /// The closure only borrows the value, so it cannot consume it:
+    ///
+    /// ```compile_fail
+    /// use unit_ext::*;
+    /// let v = vec![1].also(|v| drop::<Vec<i32>>(*v));
+    /// ```
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unit_ext::*;
+    ///
+    /// #[derive(Debug, PartialEq)]
+    /// struct User {
+    ///     name: &'static str,
+    /// }
+    ///
+    /// let user = User { name: "ferris" }
+    ///     .also(|u| println!("built {u:?}"))
+    ///     .into_some();
+    /// assert_eq!(user, Some(User { name: "ferris" }));
+    /// ```
+    #[must_use]
+    #[inline]
+    fn also<F: FnOnce(&Self)>(self, f: F) -> Self {
+        f(&self);
+        self
+    }
+
+    /// Alias for [`also`](RetExt::also) that spells out the borrow.
+    #[must_use]
+    #[inline]
+    fn also_with_ref<F: FnOnce(&Self)>(self, f: F) -> Self {
+        self.also(f)
+    }
+
+    /// Calls `f` with a mutable reference to `self`, then returns
+    /// `self`.
+    ///
+    /// The mutable counterpart of [`also`](RetExt::also); changes
+    /// made by `f` are visible in the returned value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unit_ext::*;
+    ///
+    /// let v = vec![3, 1, 2]
+    ///     .also_with_mut(|v| v.sort())
+    ///     .also_with_mut(|v| v.push(4));
+    /// assert_eq!(v, [1, 2, 3, 4]);
+    /// ```
+    #[must_use]
+    #[inline]
+    fn also_with_mut<F: FnOnce(&mut Self)>(mut self, f: F) -> Self {
+        f(&mut self);
+        self
+    }
+
+    /// Calls `f` with a reference to `self` and returns `self`
+    /// together with the value `f` produced.
+    ///
+    /// Useful for deriving metadata on the side without breaking the
+    /// chain.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unit_ext::*;
+    ///
+    /// let (words, count) = vec!["a", "b", "c"].also_convert(Vec::len);
+    /// assert_eq!(words, ["a", "b", "c"]);
+    /// assert_eq!(count, 3);
+    /// ```
+    #[must_use]
+    #[inline]
+    fn also_convert<T, F: FnOnce(&Self) -> T>(
+        self,
+        f: F,
+    ) -> (Self, T) {
+        let derived = f(&self);
+        (self, derived)
+    }
+
+    /// Pairs `self` with `other`, returning `(self, other)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unit_ext::*;
+    /// use std::collections::HashMap;
+    ///
+    /// let entry = "id".to_uppercase().zip_with(7);
+    /// assert_eq!(entry, (String::from("ID"), 7));
+    ///
+    /// let map: HashMap<_, _> = [entry].into_iter().collect();
+    /// assert_eq!(map["ID"], 7);
+    /// ```
+    #[must_use]
+    #[inline]
+    fn zip_with<U>(self, other: U) -> (Self, U) {
+        (self, other)
+    }
+
+    /// Pairs a borrow of `self` with a value computed from it.
+    ///
+    /// The borrowing counterpart of [`also_convert`](RetExt::also_convert):
+    /// `self` is not moved and stays usable afterwards.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unit_ext::*;
+    ///
+    /// let words = vec!["a", "bb", "ccc"];
+    /// let (same, longest) = words.zip_with_fn(|w| w.iter().map(|s| s.len()).max());
+    /// assert!(std::ptr::eq(same, &words));
+    /// assert_eq!(longest, Some(3));
+    /// assert_eq!(words.len(), 3);
+    /// ```
+    #[must_use]
+    #[inline]
+    fn zip_with_fn<U, F: FnOnce(&Self) -> U>(
+        &self,
+        f: F,
+    ) -> (&Self, U) {
+        (self, f(self))
+    }
+
+    /// Maps `self` to a new value with `f`.
+    ///
+    /// Behaves exactly like [`PipeExt::pipe`]; the name is for readers
+    /// who think of the step as a transformation, as in `Option::map`.
+    ///
+    /// A panic in `f` propagates to the caller:
+    ///
+    /// ```should_panic
+    /// use unit_ext::*;
+    /// let _: u8 = 0u8.map_self(|n| 1 / n);
+    /// ```
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unit_ext::*;
+    ///
+    /// assert_eq!(5.map_self(|n| n), 5);
+    ///
+    /// let len = "hello".map_self(str::len);
+    /// assert_eq!(len, 5);
+    ///
+    /// let label = 42.map_self(|n| format!("#{n}"));
+    /// assert_eq!(label, "#42");
+    /// ```
+    #[inline]
+    fn map_self<T, F: FnOnce(Self) -> T>(self, f: F) -> T {
+        self.pipe(f)
+    }
+
+    /// Passes `self` by value to `f` and returns the value `f` gives
+    /// back.
+    ///
+    /// Unlike [`TapExt::tap_mut`], `f` owns the value for the duration
+    /// of the call, so it can move fields out and rebuild it. `f` must
+    /// return the same type:
+    ///
+    /// ```compile_fail
+    /// use unit_ext::*;
+    /// let n = 5.replace_with(|n| n.to_string());
+    /// ```
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unit_ext::*;
+    ///
+    /// #[derive(Debug, PartialEq)]
+    /// struct Item {
+    ///     name: String,
+    ///     tags: Vec<&'static str>,
+    /// }
+    ///
+    /// let item = Item { name: "draft".into(), tags: vec!["new"] };
+    /// let item = item.replace_with(|mut i| {
+    ///     i.name = i.name.to_uppercase();
+    ///     i.tags = i.tags.into_iter().chain(["edited"]).collect();
+    ///     i
+    /// });
+    /// assert_eq!(item.name, "DRAFT");
+    /// assert_eq!(item.tags, ["new", "edited"]);
+    /// ```
+    #[must_use]
+    #[inline]
+    fn replace_with<F: FnOnce(Self) -> Self>(self, f: F) -> Self {
+        f(self)
+    }
+
+    /// Passes a shared reference to `self` to `f` and returns the
+    /// result, leaving `self` usable afterwards.
+    ///
+    /// Behaves exactly like [`PipeExt::pipe_ref`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unit_ext::*;
+    ///
+    /// let names = vec!["a", "bb"];
+    /// let longest = names.pipe_as_ref(|n| n.iter().map(|s| s.len()).max());
+    /// assert_eq!(longest, Some(2));
+    /// assert_eq!(names.len(), 2);
+    /// ```
+    #[inline]
+    fn pipe_as_ref<T, F: FnOnce(&Self) -> T>(&self, f: F) -> T {
+        self.pipe_ref(f)
+    }
+
+    /// Passes a mutable reference to `self` to `f` and returns the
+    /// result; changes made by `f` are visible afterwards.
+    ///
+    /// Behaves exactly like [`PipeExt::pipe_mut`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unit_ext::*;
+    ///
+    /// struct Builder {
+    ///     parts: Vec<&'static str>,
+    /// }
+    ///
+    /// let mut b = Builder { parts: vec![] };
+    /// b.pipe_as_mut(|b| b.parts.push("head"));
+    /// let count = b.pipe_as_mut(|b| {
+    ///     b.parts.push("body");
+    ///     b.parts.len()
+    /// });
+    /// assert_eq!(count, 2);
+    /// assert_eq!(b.parts, ["head", "body"]);
+    /// ```
+    #[inline]
+    fn pipe_as_mut<T, F: FnOnce(&mut Self) -> T>(
+        &mut self,
+        f: F,
+    ) -> T {
+        self.pipe_mut(f)
+    }
+
+    /// Wraps `self` in a one-element [`Vec`].
+    ///
+    /// Named so it cannot be shadowed by the inherent
+    /// `<Box<[T]>>::into_vec`: a boxed slice is wrapped, not unpacked.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unit_ext::*;
+    ///
+    /// fn ids(raw: &str) -> Vec<u32> {
+    ///     match raw.parse::<u32>() {
+    ///         Ok(id) => id.into_single_vec(),
+    ///         Err(_) => raw.split(',').filter_map(|s| s.parse().ok()).collect(),
+    ///     }
+    /// }
+    ///
+    /// assert_eq!(ids("7"), [7]);
+    /// assert_eq!(ids("1,2"), [1, 2]);
+    /// assert_eq!(ids("7").len(), 1);
+    ///
+    /// let boxed: Box<[u8]> = Box::new([1, 2]);
+    /// let wrapped: Vec<Box<[u8]>> = boxed.into_single_vec();
+    /// assert_eq!(wrapped.len(), 1);
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    #[inline]
+    fn into_single_vec(self) -> Vec<Self> {
+        alloc::vec![self]
+    }
+
+    /// Wraps `self` in a one-element boxed slice.
+    ///
+    /// Named so it cannot be shadowed by the inherent
+    /// `Vec::into_boxed_slice`: a `Vec` is wrapped, not converted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unit_ext::*;
+    ///
+    /// let items: Box<[&str]> = "only".into_single_boxed_slice();
+    /// assert_eq!(items.len(), 1);
+    /// assert_eq!(items[0], "only");
+    ///
+    /// let rows: Box<[Vec<u8>]> = vec![1, 2].into_single_boxed_slice();
+    /// assert_eq!(rows.len(), 1);
+    /// assert_eq!(rows[0], [1, 2]);
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    #[inline]
+    fn into_single_boxed_slice(self) -> Box<[Self]> {
+        Box::new([self])
+    }
+
+    /// Moves `self` into a [`Box`].
+    ///
+    /// The value-first counterpart of `UnitExt::ret_box`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unit_ext::*;
+    /// use std::fmt::Display;
+    ///
+    /// let shown: Box<dyn Display> = 42.into_boxed();
+    /// assert_eq!(shown.to_string(), "42");
+    /// assert_eq!(*"ok".into_boxed(), "ok");
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    #[inline]
+    fn into_boxed(self) -> Box<Self> {
+        Box::new(self)
+    }
+
+    /// Moves `self` into an [`Rc`].
+    ///
+    /// The value-first counterpart of `UnitExt::ret_rc`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unit_ext::*;
+    /// use std::rc::Rc;
+    ///
+    /// let shared = vec![1, 2].into_rc();
+    /// let other = Rc::clone(&shared);
+    /// assert_eq!(*other, [1, 2]);
+    /// assert_eq!(Rc::strong_count(&shared), 2);
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    #[inline]
+    fn into_rc(self) -> Rc<Self> {
+        Rc::new(self)
+    }
+
+    /// Moves `self` into an [`Arc`].
+    ///
+    /// The value-first counterpart of `UnitExt::ret_arc`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unit_ext::*;
+    /// use std::{fmt::Debug, sync::Arc, thread};
+    ///
+    /// let shared: Arc<dyn Debug + Send + Sync> = "config".into_arc();
+    /// let worker = {
+    ///     let shared = Arc::clone(&shared);
+    ///     thread::spawn(move || format!("{shared:?}"))
+    /// };
+    /// assert_eq!(worker.join().unwrap(), "\"config\"");
+    /// ```
+    #[cfg(feature = "std")]
+    #[must_use]
+    #[inline]
+    fn into_arc(self) -> Arc<Self> {
+        Arc::new(self)
+    }
+}
+
+/// Conversion of any value into unit `()`, the reverse of
+/// [`UnitExt`].
+///
+/// `into_unit` is the idiomatic spelling of [`RetExt::discard_self`]
+/// for readers used to the `Into`/`From` conventions; the two are
+/// interchangeable at every call site.
+///
+/// # Examples
+///
+/// ```
+/// #![deny(warnings)]
+/// use unit_ext::*;
+///
+/// #[must_use]
+/// fn noisy(x: i32) -> i32 {
+///     x
+/// }
+///
+/// (0..3).for_each(|n| noisy(n).into_unit());
+///
+/// let next = noisy(1).into_unit().ret_some(2);
+/// assert_eq!(next, Some(2));
+/// ```
+pub trait IntoUnit: Sized {
+    /// Consumes `self`, returning `()`.
+    ///
+    /// Equivalent to `let _ = self;`.
+    #[inline]
+    fn into_unit(self) {
+        let _ = self;
+    }
+}
+
+/// Extension methods for passing any value into a function, the pipe
+/// idiom.
+///
+/// Where [`RetExt`] throws a value away, `PipeExt` hands it on, so a
+/// free function can be applied without breaking the chain.
+///
+/// # Examples
+///
+/// ```
+/// use unit_ext::*;
+///
+/// fn double(n: i32) -> i32 {
+///     n * 2
+/// }
+///
+/// let n = 3.pipe(double).pipe(|n| n + 1);
+/// assert_eq!(n, 7);
+/// ```
+pub trait PipeExt: Sized {
+    /// Passes `self` by value to `f` and returns the result.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unit_ext::*;
+    ///
+    /// let len = "hello".pipe(str::len);
+    /// assert_eq!(len, 5);
+    ///
+    /// let opt = 5.pipe(Some);
+    /// assert_eq!(opt, Some(5));
+    /// ```
+    #[inline]
+    fn pipe<T, F: FnOnce(Self) -> T>(self, f: F) -> T {
+        f(self)
+    }
+
+    /// Passes a shared reference to `self` to `f` and returns the
+    /// result.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unit_ext::*;
+    ///
+    /// let v = vec![1, 2, 3];
+    /// let total: i32 = v.pipe_ref(|v| v.iter().sum());
+    /// let len = v.pipe_ref(Vec::len);
+    /// assert_eq!((total, len), (6, 3));
+    /// ```
+    #[inline]
+    fn pipe_ref<T, F: FnOnce(&Self) -> T>(&self, f: F) -> T {
+        f(self)
+    }
+
+    /// Passes a mutable reference to `self` to `f` and returns the
+    /// result.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unit_ext::*;
+    ///
+    /// fn bump(n: &mut i32) -> i32 {
+    ///     *n += 1;
+    ///     *n
+    /// }
+    ///
+    /// let mut v = vec![3, 1, 2];
+    /// v.pipe_mut(|v| v.sort());
+    /// let popped = v.pipe_mut(Vec::pop);
+    /// assert_eq!((v, popped), (vec![1, 2], Some(3)));
+    ///
+    /// let mut n = 1;
+    /// assert_eq!(n.pipe_mut(bump), 2);
+    /// assert_eq!(n, 2);
+    /// ```
+    #[inline]
+    fn pipe_mut<T, F: FnOnce(&mut Self) -> T>(&mut self, f: F) -> T {
+        f(self)
+    }
+}
+
+/// Extension methods for running a side effect on a value mid-chain
+/// and getting the same value back.
+///
+/// # Examples
+///
+/// ```
+/// use unit_ext::*;
+///
+/// let v = vec![3, 1, 2]
+///     .tap(|v| println!("unsorted: {v:?}"))
+///     .tap_mut(|v| v.sort());
+/// assert_eq!(v, [1, 2, 3]);
+/// ```
+pub trait TapExt: Sized {
+    /// Calls `f` with a shared reference to `self`, then returns
+    /// `self` unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unit_ext::*;
+    ///
+    /// let mut seen = 0;
+    /// let n = 5.tap(|n| seen = *n);
+    /// assert_eq!((n, seen), (5, 5));
+    /// ```
+    #[must_use]
+    #[inline]
+    fn tap<F: FnOnce(&Self)>(self, f: F) -> Self {
+        f(&self);
+        self
+    }
+
+    /// Calls `f` with a mutable reference to `self`, then returns
+    /// `self`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unit_ext::*;
+    ///
+    /// let s = String::from("a").tap_mut(|s| s.push('b'));
+    /// assert_eq!(s, "ab");
+    /// ```
+    #[must_use]
+    #[inline]
+    fn tap_mut<F: FnOnce(&mut Self)>(mut self, f: F) -> Self {
+        f(&mut self);
+        self
+    }
+
+    /// Prints `self` with its [`Debug`](core::fmt::Debug) impl to
+    /// stderr, then returns it unchanged.
+    ///
+    /// Printing only happens when `unit_ext` itself is compiled with
+    /// `debug_assertions`; in release builds this is a no-op. Requires
+    /// the `std` feature.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unit_ext::*;
+    ///
+    /// let v = Some(3).tap_dbg().map(|n| n * 2).tap_dbg();
+    /// assert_eq!(v, Some(6));
+    /// ```
+    #[cfg(feature = "std")]
+    #[must_use]
+    #[inline]
+    fn tap_dbg(self) -> Self
+    where
+        Self: core::fmt::Debug,
+    {
+        emit_if_debug(&self, |v| std::eprintln!("{v:?}"));
+        self
+    }
+}
+
+/// Calls `emit` with `value` only when compiled with
+/// `debug_assertions`.
+///
+/// Split out of [`TapExt::tap_dbg`] so the build-dependent branch can
+/// be tested without capturing stderr.
+#[cfg(feature = "std")]
+#[inline]
+fn emit_if_debug<T: ?Sized, F: FnOnce(&T)>(value: &T, emit: F) {
+    if cfg!(debug_assertions) {
+        emit(value);
+    }
+}
+
+/// Generates one `log_*` method per `log` level.
+#[cfg(feature = "log")]
+macro_rules! log_at {
+    ($($name:ident => $level:ident),* $(,)?) => {
+        $(
+            #[doc = concat!(
+                "Logs `self` at [`log::Level::", stringify!($level),
+                "`] as `\"{msg}: {self:?}\"`, then returns it unchanged.",
+            )]
+            #[must_use]
+            #[inline]
+            fn $name(self, msg: &str) -> Self
+            where
+                Self: fmt::Debug,
+            {
+                log::log!(log::Level::$level, "{msg}: {self:?}");
+                self
+            }
+        )*
+    };
+}
+
+/// Extension methods for recording any value through the [`log`]
+/// facade without breaking the chain.
+///
+/// The typed, levelled counterparts of [`TapExt::tap_dbg`]: each method
+/// logs `"{msg}: {self:?}"` and hands `self` back. Nothing is printed
+/// unless a logger is installed. Requires the `log` feature.
+///
+/// # Examples
+///
+/// ```
+/// use unit_ext::*;
+/// use log::{Level, LevelFilter, Log, Metadata, Record};
+/// use std::sync::Mutex;
+///
+/// struct Capture(Mutex<Vec<(Level, String)>>);
+///
+/// impl Log for Capture {
+///     fn enabled(&self, _: &Metadata<'_>) -> bool {
+///         true
+///     }
+///     fn log(&self, record: &Record<'_>) {
+///         let line = record.args().to_string();
+///         self.0.lock().unwrap().push((record.level(), line));
+///     }
+///     fn flush(&self) {}
+/// }
+///
+/// static LOGGER: Capture = Capture(Mutex::new(Vec::new()));
+/// log::set_logger(&LOGGER).unwrap();
+/// log::set_max_level(LevelFilter::Trace);
+///
+/// let rows = vec![1, 2].log_info("query returned").len().log_debug("rows");
+/// assert_eq!(rows, 2);
+///
+/// let records = LOGGER.0.lock().unwrap();
+/// assert_eq!(records[0], (Level::Info, "query returned: [1, 2]".into()));
+/// assert_eq!(records[1], (Level::Debug, "rows: 2".into()));
+/// ```
+#[cfg(feature = "log")]
+pub trait LogExt: Sized {
+    log_at! {
+        log_trace => Trace,
+        log_debug => Debug,
+        log_info => Info,
+        log_warn => Warn,
+        log_error => Error,
+    }
+}
+
+/// Extension methods for `bool` that turn a condition into an
+/// [`Option`] or a [`Result`].
+///
+/// Complements [`bool::then`] and [`bool::then_some`] with the
+/// `Result` variants the standard library lacks.
+///
+/// # Examples
+///
+/// ```
+/// use unit_ext::*;
+///
+/// let port = 8080;
+/// let res: Result<u16, &str> =
+///     (port > 1024).then_ret_ok(port, "privileged port");
+/// assert_eq!(res, Ok(8080));
+/// ```
+pub trait BoolExt: Sized {
+    /// Returns `Some(value)` if `self` is `true`, otherwise [`None`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unit_ext::*;
+    /// assert_eq!(true.then_ret_some(1), Some(1));
+    /// assert_eq!(false.then_ret_some(1), None);
+    /// ```
+    #[must_use]
+    fn then_ret_some<T>(self, value: T) -> Option<T>;
+
+    /// Returns `Some(f())` if `self` is `true`, otherwise [`None`].
+    ///
+    /// `f` is only called when `self` is `true`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unit_ext::*;
+    /// assert_eq!(true.then_ret_some_with(|| 1), Some(1));
+    /// assert_eq!(false.then_ret_some_with(|| -> u8 { panic!() }), None);
+    /// ```
+    #[must_use]
+    fn then_ret_some_with<T, F: FnOnce() -> T>(
+        self,
+        f: F,
+    ) -> Option<T>;
+
+    /// Returns `Ok(value)` if `self` is `true`, otherwise `Err(err)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unit_ext::*;
+    /// assert_eq!(true.then_ret_ok::<_, &str>(1, "no"), Ok(1));
+    /// assert_eq!(false.then_ret_ok::<u8, _>(1, "no"), Err("no"));
+    /// ```
+    #[must_use]
+    fn then_ret_ok<T, E>(self, value: T, err: E) -> Result<T, E>;
+
+    /// Returns `Ok(value)` if `self` is `false`, otherwise `Err(err)`.
+    ///
+    /// The inverse of [`then_ret_ok`](BoolExt::then_ret_ok), handy for
+    /// conditions that describe the failure case.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unit_ext::*;
+    /// let is_empty = "".is_empty();
+    /// assert_eq!(is_empty.else_ret_ok::<u8, _>(1, "empty"), Err("empty"));
+    /// assert_eq!(false.else_ret_ok::<_, &str>(1, "empty"), Ok(1));
+    /// ```
+    #[must_use]
+    fn else_ret_ok<T, E>(self, value: T, err: E) -> Result<T, E>;
+}
+
+impl BoolExt for bool {
+    #[inline]
+    fn then_ret_some<T>(self, value: T) -> Option<T> {
+        self.then_some(value)
+    }
+
+    #[inline]
+    fn then_ret_some_with<T, F: FnOnce() -> T>(
+        self,
+        f: F,
+    ) -> Option<T> {
+        self.then(f)
+    }
+
+    #[inline]
+    fn then_ret_ok<T, E>(self, value: T, err: E) -> Result<T, E> {
+        if self { Ok(value) } else { Err(err) }
+    }
+
+    #[inline]
+    fn else_ret_ok<T, E>(self, value: T, err: E) -> Result<T, E> {
+        (!self).then_ret_ok(value, err)
+    }
+}
+
+/// Names the parts of an [`Option`], so that methods such as
+/// [`OptionExt::flatten_once`] can infer the inner type from the
+/// receiver.
+///
+/// Implemented for every `Option<T>`. The trait is sealed, so it
+/// cannot be implemented outside this crate.
+pub trait OptionShape: private::SealedShape {
+    /// The `T` in `Option<T>`.
+    type Inner;
+
+    /// Returns `self` as a plain [`Option`].
+    fn into_option(self) -> Option<Self::Inner>;
+}
+
+impl<T> OptionShape for Option<T> {
+    type Inner = T;
+
+    #[inline]
+    fn into_option(self) -> Option<T> {
+        self
+    }
+}
+
+/// Names the parts of a [`Result`], so that methods such as
+/// [`ResultExt::flatten_ok`] can infer the inner types from the
+/// receiver.
+///
+/// Implemented for every `Result<T, E>`. The trait is sealed, so it
+/// cannot be implemented outside this crate.
+pub trait ResultShape: private::SealedShape {
+    /// The `T` in `Result<T, E>`.
+    type Ok;
+    /// The `E` in `Result<T, E>`.
+    type Err;
+
+    /// Returns `self` as a plain [`Result`].
+    fn into_result(self) -> Result<Self::Ok, Self::Err>;
+}
+
+impl<T, E> ResultShape for Result<T, E> {
+    type Ok = T;
+    type Err = E;
+
+    #[inline]
+    fn into_result(self) -> Result<T, E> {
+        self
+    }
+}
+
+/// Extension methods specific to [`Option<T>`].
+///
+/// # Examples
+///
+/// ```
+/// use unit_ext::*;
+///
+/// let port: Result<u16, ()> = None.ok_or_unit();
+/// assert_eq!(port, Err(()));
+/// ```
+pub trait OptionExt<T>: Sized {
+    /// Converts to `Ok(value)` or to `Err(E::default())`.
+    ///
+    /// Mostly used with `E = ()` when the absence needs no further
+    /// description.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unit_ext::*;
+    ///
+    /// fn find(id: u8) -> Result<&'static str, ()> {
+    ///     [(1, "one")]
+    ///         .iter()
+    ///         .find(|(k, _)| *k == id)
+    ///         .map(|(_, v)| *v)
+    ///         .ok_or_unit()
+    ///         .or_else(|()| eprintln!("{id} not found").ret_err(()))
+    /// }
+    ///
+    /// assert_eq!(find(1), Ok("one"));
+    /// assert_eq!(find(2), Err(()));
+    /// ```
+    #[must_use]
+    fn ok_or_unit<E: Default>(self) -> Result<T, E>;
+
+    /// Turns an `Option<Result<U, E>>` into a `Result<Option<U>, E>`.
+    ///
+    /// `None` becomes `Ok(None)`, `Some(Ok(v))` becomes `Ok(Some(v))`
+    /// and `Some(Err(e))` becomes `Err(e)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unit_ext::*;
+    ///
+    /// let parse = |s: Option<&str>| {
+    ///     s.map(str::parse::<u8>).transpose_into_result()
+    /// };
+    ///
+    /// assert_eq!(parse(Some("4")), Ok(Some(4)));
+    /// assert_eq!(parse(None), Ok(None));
+    /// assert!(parse(Some("x")).is_err());
+    ///
+    /// let skipped: Option<Result<u8, ()>> = ().ret_none();
+    /// assert_eq!(skipped.transpose_into_result(), Ok(None));
+    /// ```
+    #[doc(alias = "transpose_option_result")]
+    #[must_use]
+    fn transpose_into_result(self) -> Result<Option<T::Ok>, T::Err>
+    where
+        T: ResultShape;
+
+    /// Removes one level of nesting from an `Option<Option<U>>`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unit_ext::*;
+    ///
+    /// let nested = Some(().ret_some(3));
+    /// assert_eq!(nested.flatten_once(), Some(3));
+    ///
+    /// let empty: Option<Option<i32>> = Some(().ret_none());
+    /// assert_eq!(empty.flatten_once(), None);
+    /// ```
+    #[must_use]
+    fn flatten_once(self) -> Option<T::Inner>
+    where
+        T: OptionShape;
+
+    /// Returns the contained value or `T::default()`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unit_ext::*;
+    ///
+    /// let name: Option<String> = ().ret_none();
+    /// let name = name
+    ///     .unwrap_or_unit()
+    ///     .also(|s| println!("name is {s:?}"));
+    /// assert_eq!(name, "");
+    /// assert_eq!(().ret_some(5).unwrap_or_unit(), 5);
+    /// ```
+    #[must_use]
+    fn unwrap_or_unit(self) -> T
+    where
+        T: Default;
+}
+
+impl<T> OptionExt<T> for Option<T> {
+    #[inline]
+    fn ok_or_unit<E: Default>(self) -> Result<T, E> {
+        self.ok_or_else(E::default)
+    }
+
+    #[inline]
+    fn transpose_into_result(self) -> Result<Option<T::Ok>, T::Err>
+    where
+        T: ResultShape,
+    {
+        self.map(ResultShape::into_result).transpose()
+    }
+
+    #[inline]
+    fn flatten_once(self) -> Option<T::Inner>
+    where
+        T: OptionShape,
+    {
+        self.and_then(OptionShape::into_option)
+    }
+
+    #[inline]
+    fn unwrap_or_unit(self) -> T
+    where
+        T: Default,
+    {
+        self.unwrap_or_default()
+    }
+}
+
+/// Extension methods specific to [`Result<T, E>`].
+///
+/// # Examples
+///
+/// ```
+/// use unit_ext::*;
+///
+/// let first = "1,x,3"
+///     .split(',')
+///     .filter_map(|s| s.parse::<u8>().ok_discard())
+///     .next();
+/// assert_eq!(first, Some(1));
+/// ```
+pub trait ResultExt<T, E>: Sized {
+    /// Converts to `Some(value)` on `Ok`, dropping the error.
+    ///
+    /// Same as [`Result::ok`], but the name makes the discarded error
+    /// visible at the call site.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unit_ext::*;
+    ///
+    /// let ok = ().ret_ok::<_, &str>(1).ok_discard();
+    /// assert_eq!(ok, Some(1));
+    ///
+    /// let err = ().ret_err::<u8, _>("boom").ok_discard();
+    /// assert_eq!(err, None);
+    /// ```
+    #[must_use]
+    fn ok_discard(self) -> Option<T>;
+
+    /// Converts to `Some(error)` on `Err`, dropping the success value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unit_ext::*;
+    ///
+    /// let err = ().ret_err::<u8, _>("boom").err_discard();
+    /// assert_eq!(err, Some("boom"));
+    ///
+    /// let ok = ().ret_ok::<_, &str>(1).err_discard();
+    /// assert_eq!(ok, None);
+    /// ```
+    #[must_use]
+    fn err_discard(self) -> Option<E>;
+
+    /// Turns a `Result<Option<U>, E>` into an `Option<Result<U, E>>`.
+    ///
+    /// `Ok(None)` becomes `None`, `Ok(Some(v))` becomes `Some(Ok(v))`
+    /// and `Err(e)` becomes `Some(Err(e))`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unit_ext::*;
+    ///
+    /// let found: Result<Option<u8>, &str> = ().ret_ok(Some(1));
+    /// assert_eq!(found.transpose_opt(), Some(Ok(1)));
+    ///
+    /// let missing: Result<Option<u8>, &str> = ().ret_ok(None);
+    /// assert_eq!(missing.transpose_opt(), None);
+    ///
+    /// let failed: Result<Option<u8>, &str> = ().ret_err("io");
+    /// assert_eq!(failed.transpose_opt(), Some(Err("io")));
+    /// ```
+    #[doc(alias = "transpose_result_option")]
+    #[must_use]
+    fn transpose_opt(self) -> Option<Result<T::Inner, E>>
+    where
+        T: OptionShape;
+
+    /// Removes one level of nesting from a `Result<Result<U, E>, E>`.
+    ///
+    /// A stable stand-in for the unstable `Result::flatten`:
+    /// `Ok(Ok(v))` becomes `Ok(v)`, while both `Ok(Err(e))` and
+    /// `Err(e)` become `Err(e)`. It lives on `ResultExt` rather than
+    /// [`RetExt`] so it cannot overlap with the blanket impl for every
+    /// type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unit_ext::*;
+    ///
+    /// let parse = |s: &str| -> Result<Result<u8, String>, String> {
+    ///     ().ret_ok(s.parse::<u8>().map_err(|e| e.to_string()))
+    /// };
+    ///
+    /// assert_eq!(parse("3").flatten_ok(), Ok(3));
+    /// assert!(parse("x").flatten_ok().is_err());
+    ///
+    /// let ok_ok: Result<Result<u8, &str>, &str> = Ok(Ok(1));
+    /// let ok_err: Result<Result<u8, &str>, &str> = Ok(Err("inner"));
+    /// let err: Result<Result<u8, &str>, &str> = Err("outer");
+    /// assert_eq!(ok_ok.flatten_ok(), Ok(1));
+    /// assert_eq!(ok_err.flatten_ok(), Err("inner"));
+    /// assert_eq!(err.flatten_ok(), Err("outer"));
+    /// ```
+    #[must_use]
+    fn flatten_ok(self) -> Result<T::Ok, E>
+    where
+        T: ResultShape<Err = E>;
+
+    /// Returns the contained error or `E::default()`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unit_ext::*;
+    ///
+    /// let msg = ().ret_err::<u8, _>(String::from("boom"));
+    /// assert_eq!(msg.unwrap_err_or_default(), "boom");
+    ///
+    /// let msg = ().ret_ok::<_, String>(1);
+    /// assert_eq!(msg.unwrap_err_or_default(), "");
+    /// ```
+    #[must_use]
+    fn unwrap_err_or_default(self) -> E
+    where
+        E: Default;
+}
+
+impl<T, E> ResultExt<T, E> for Result<T, E> {
+    #[inline]
+    fn ok_discard(self) -> Option<T> {
+        self.ok()
+    }
+
+    #[inline]
+    fn err_discard(self) -> Option<E> {
+        self.err()
+    }
+
+    #[inline]
+    fn transpose_opt(self) -> Option<Result<T::Inner, E>>
+    where
+        T: OptionShape,
+    {
+        self.map(OptionShape::into_option).transpose()
+    }
+
+    #[inline]
+    fn flatten_ok(self) -> Result<T::Ok, E>
+    where
+        T: ResultShape<Err = E>,
+    {
+        self.and_then(ResultShape::into_result)
+    }
+
+    #[inline]
+    fn unwrap_err_or_default(self) -> E
+    where
+        E: Default,
+    {
+        self.err().unwrap_or_default()
+    }
+}
+
+/// Calls [`UnitExt::ret_format`] with `format_args!` built from the
+/// remaining arguments.
 ///
-/// The helpers make intent obvious when chaining iterator or async
-/// pipelines.
+/// # Examples
+///
+/// ```
+/// use unit_ext::*;
+///
+/// let (a, b) = (1, 2);
+/// let s = ret_format_args!(println!("adding"), "{a} + {b} = {}", a + b);
+/// assert_eq!(s, "1 + 2 = 3");
+/// ```
+#[cfg(feature = "alloc")]
+#[macro_export]
+macro_rules! ret_format_args {
+    ($unit:expr, $($arg:tt)*) => {
+        $crate::UnitExt::ret_format($unit, ::core::format_args!($($arg)*))
+    };
+}
+
+/// Extension methods for pairs that keep one component and discard
+/// the other.
 ///
 /// # Examples
 ///
 /// ```
 /// use unit_ext::*;
 ///
-/// (0..3).for_each(|n| n.discard_ret());
-/// let x: Option<usize> = Some(0).discard_self().ret_some(1);
+/// let parsed = ("42".parse::<u8>(), "metadata");
+/// assert_eq!(parsed.discard_second(), Ok(42));
 /// ```
-pub trait RetExt: Sized {
-    /// Discards `self`, returning `()`.
+pub trait Tuple2Ext<A, B>: Sized {
+    /// Drops the first component and returns the second.
     ///
-    /// Equivalent to `let _ = self;`.
+    /// # Examples
+    ///
+    /// ```
+    /// use unit_ext::*;
+    /// assert_eq!(("log line", 7).discard_first(), 7);
+    /// ```
+    #[must_use]
+    fn discard_first(self) -> B;
+
+    /// Drops the second component and returns the first.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unit_ext::*;
+    /// assert_eq!((7, "log line").discard_second(), 7);
+    /// ```
+    #[must_use]
+    fn discard_second(self) -> A;
+
+    /// Returns the pair unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unit_ext::*;
+    /// let (a, b) = (1, 'x').into_tuple();
+    /// assert_eq!((a, b), (1, 'x'));
+    /// ```
+    #[must_use]
+    fn into_tuple(self) -> (A, B);
+}
+
+impl<A, B> Tuple2Ext<A, B> for (A, B) {
     #[inline]
-    fn discard_self(self) {
-        let _ = self;
+    fn discard_first(self) -> B {
+        self.1
     }
 
-    /// Alias for [`discard_self`](RetExt::discard_self)
     #[inline]
-    fn discard_ret(self) {
-        self.discard_self();
+    fn discard_second(self) -> A {
+        self.0
+    }
+
+    #[inline]
+    fn into_tuple(self) -> (A, B) {
+        self
+    }
+}
+
+/// Extension methods for triples that discard one component and keep
+/// the other two.
+///
+/// # Examples
+///
+/// ```
+/// use unit_ext::*;
+///
+/// let (key, value) = ("id", 7, "took 3ms").discard_third();
+/// assert_eq!((key, value), ("id", 7));
+/// ```
+pub trait Tuple3Ext<A, B, C>: Sized {
+    /// Drops the first component and returns the other two.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unit_ext::*;
+    /// assert_eq!((1, 2, 3).discard_first(), (2, 3));
+    /// ```
+    #[must_use]
+    fn discard_first(self) -> (B, C);
+
+    /// Drops the second component and returns the other two.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unit_ext::*;
+    /// assert_eq!((1, 2, 3).discard_second(), (1, 3));
+    /// ```
+    #[must_use]
+    fn discard_second(self) -> (A, C);
+
+    /// Drops the third component and returns the other two.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unit_ext::*;
+    /// assert_eq!((1, 2, 3).discard_third(), (1, 2));
+    /// ```
+    #[must_use]
+    fn discard_third(self) -> (A, B);
+
+    /// Returns the triple unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unit_ext::*;
+    /// assert_eq!((1, 'x', "y").into_tuple(), (1, 'x', "y"));
+    /// ```
+    #[must_use]
+    fn into_tuple(self) -> (A, B, C);
+}
+
+impl<A, B, C> Tuple3Ext<A, B, C> for (A, B, C) {
+    #[inline]
+    fn discard_first(self) -> (B, C) {
+        (self.1, self.2)
+    }
+
+    #[inline]
+    fn discard_second(self) -> (A, C) {
+        (self.0, self.2)
+    }
+
+    #[inline]
+    fn discard_third(self) -> (A, B) {
+        (self.0, self.1)
+    }
+
+    #[inline]
+    fn into_tuple(self) -> (A, B, C) {
+        self
     }
 }
 
-impl UnitExt for () {}
+/// Implements [`UnitExt`] for user-defined zero-sized types.
+///
+/// Each listed type also gets a `From<T> for ()` impl, so it can be
+/// turned back into the real unit with `.into()`. Appending
+/// `= <expr>` additionally generates `From<()> for T`, using the
+/// expression to build the value. Non-zero-sized types are rejected at
+/// compile time.
+///
+/// # Examples
+///
+/// ```
+/// use unit_ext::*;
+///
+/// mod markers {
+///     pub struct Done;
+///     pub struct Logged;
+///     struct Hidden;
+///
+///     unit_ext::unit_ext_for!(Done, Logged = Logged, Hidden);
+///
+///     pub fn hidden() -> Option<u8> {
+///         use unit_ext::UnitExt;
+///         Hidden.ret_some(1)
+///     }
+/// }
+///
+/// use markers::{Done, Logged};
+///
+/// assert_eq!(Done.ret_ok::<_, ()>(1), Ok(1));
+/// assert_eq!(markers::hidden(), Some(1));
+///
+/// let (): () = Done.into();
+/// let _marker: Logged = ().into();
+/// ```
+///
+/// ```compile_fail
+/// struct NotZeroSized(u8);
+/// unit_ext::unit_ext_for!(NotZeroSized);
+/// ```
+#[macro_export]
+macro_rules! unit_ext_for {
+    ($($t:ty $(= $value:expr)?),+ $(,)?) => {$(
+        const _: () = ::core::assert!(
+            ::core::mem::size_of::<$t>() == 0,
+            "unit_ext_for! only accepts zero-sized types",
+        );
+
+        impl $crate::UnitExt for $t {}
+
+        impl ::core::convert::From<$t> for () {
+            #[inline]
+            fn from(_: $t) -> Self {}
+        }
+
+        $(
+            impl ::core::convert::From<()> for $t {
+                #[inline]
+                fn from((): ()) -> Self {
+                    $value
+                }
+            }
+        )?
+    )+};
+}
+
+mod private {
+    pub trait Sealed {}
+
+    impl Sealed for () {}
+    impl Sealed for core::convert::Infallible {}
+    impl<T: ?Sized> Sealed for core::marker::PhantomData<T> {}
+
+    pub trait SealedShape {}
+
+    impl<T> SealedShape for Option<T> {}
+    impl<T, E> SealedShape for Result<T, E> {}
+}
+
+/// Marker for the zero-sized types that behave as unit and get
+/// [`UnitExt`] through a blanket impl.
+///
+/// Implemented for `()`, [`Infallible`](core::convert::Infallible) and
+/// [`PhantomData<T>`]. The trait is sealed, so it cannot be
+/// implemented outside this crate; use [`unit_ext_for!`] to give your
+/// own zero-sized types the helpers.
+///
+/// # Examples
+///
+/// ```
+/// use unit_ext::*;
+/// use std::marker::PhantomData;
+///
+/// let marker: PhantomData<String> = PhantomData;
+/// assert_eq!(marker.ret_ok::<_, ()>(1), Ok(1));
+/// assert_eq!(marker.ret_some("hi"), Some("hi"));
+///
+/// fn is_unit_like<U: UnitLike>(_: U) -> bool {
+///     true
+/// }
+/// assert!(is_unit_like(()));
+/// ```
+///
+/// Types with data are not unit-like and get no helpers:
+///
+/// ```compile_fail
+/// use unit_ext::*;
+/// struct Meters(u32);
+/// let _: Result<u8, ()> = Meters(1).ret_ok(1);
+/// ```
+///
+/// and the trait cannot be implemented by hand:
+///
+/// ```compile_fail
+/// struct Marker;
+/// impl unit_ext::UnitLike for Marker {}
+/// ```
+pub trait UnitLike: private::Sealed {}
+
+impl<T: private::Sealed> UnitLike for T {}
+
+impl<T: UnitLike> UnitExt for T {}
 impl<T> RetExt for T {}
+impl<T> IntoUnit for T {}
+impl<T> PipeExt for T {}
+impl<T> TapExt for T {}
+#[cfg(feature = "log")]
+impl<T> LogExt for T {}
+
+/// Checks backing the zero-overhead promise: every [`UnitLike`]
+/// receiver is zero-sized, and the heap-free helpers never touch the
+/// allocator.
+#[cfg(test)]
+mod overhead_assertions {
+    extern crate std;
+
+    use super::*;
+    use core::alloc::{GlobalAlloc, Layout};
+    use core::convert::Infallible;
+    use core::hint::black_box;
+    use core::marker::PhantomData;
+    use core::mem::{align_of, size_of};
+    use std::alloc::System;
+
+    const _: () =
+        assert!(size_of::<()>() == 0 && align_of::<()>() == 1);
+    const _: () = assert!(size_of::<Infallible>() == 0);
+    const _: () = assert!(size_of::<PhantomData<[u64; 8]>>() == 0);
+
+    std::thread_local! {
+        // Per thread, so allocations made by the test harness on
+        // other threads don't leak into the count.
+        static ALLOCS: Cell<usize> = const { Cell::new(0) };
+    }
+
+    struct Counting;
+
+    unsafe impl GlobalAlloc for Counting {
+        unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+            let _ = ALLOCS.try_with(|n| n.set(n.get() + 1));
+            // SAFETY: forwarded unchanged to the system allocator.
+            unsafe { System.alloc(layout) }
+        }
+
+        unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+            // SAFETY: `ptr` was allocated by `System` above.
+            unsafe { System.dealloc(ptr, layout) }
+        }
+    }
+
+    #[global_allocator]
+    static GLOBAL: Counting = Counting;
+
+    fn allocs() -> usize {
+        ALLOCS.with(Cell::get)
+    }
+
+    macro_rules! no_alloc {
+        ($($e:expr;)*) => {$(
+            let before = allocs();
+            let _value = black_box($e);
+            assert_eq!(allocs(), before, "`{}` allocated", stringify!($e));
+        )*};
+    }
+
+    #[test]
+    fn test_no_extra_alloc() {
+        let mut out = [0u8; 4];
+        let waker = ().ret_noop_waker();
+        let mut slot = 4;
+
+        no_alloc! {
+            ().ret(1);
+            ().ret_with(|| 1);
+            ().ret_fn_once(1)();
+            ().ret_default::<u8>();
+            ().ret_if_then_else(true, 1, 2);
+            ().ret_if_then_else_lazy(false, || 1, || 2);
+            ().ret_none::<u8>();
+            ().ret_some([0u8; 64]);
+            ().ret_some_with(|| 1);
+            ().ret_some_default::<u8>();
+            ().ret_some_if(true, 1);
+            ().ret_some_if_with(true, || 1);
+            ().ret_none_if(true, 1);
+            ().ret_none_if_with(false, || 1);
+            ().ret_option_from_fn(|| Some(1));
+            ().ret_default_if::<u8>(true);
+            ().ret_default_unless::<u8>(false);
+            ().ret_some_chain(1, Some);
+            ().ret_map_or(Some(1), 0, |n| n + 1);
+            ().ret_and_then_option(Some(1), Some);
+            ().ret_err::<u8, _>(1);
+            ().ret_err_with::<u8, _, _>(|| 1);
+            ().ret_err_default::<u8, u8>();
+            ().ret_ok::<_, ()>(1);
+            ().ret_ok_with::<_, (), _>(|| 1);
+            ().ret_ok_default::<u8, ()>();
+            ().ret_fmt_ok();
+            ().ret_ok_if(true, 1, 2);
+            ().ret_ok_if_with(false, || 1, || 2);
+            ().ret_err_if(true, 1, 2);
+            ().ret_err_if_with(false, || 1, || 2);
+            ().ret_ok_some::<_, ()>(1);
+            ().ret_ok_none::<u8, ()>();
+            ().ret_err_some::<u8, _>(1);
+            ().ret_err_none::<u8, u8>();
+            ().ret_some_ok::<_, ()>(1);
+            ().ret_some_err::<u8, _>(1);
+            ().ret_result_from_fn(|| Ok::<_, ()>(1));
+            ().ret_ok_if_some(Some(1), ());
+            ().ret_ok_if_some_with(None::<u8>, || ());
+            ().ret_some_if_ok(Ok::<_, ()>(1));
+            ().ret_ok_chain(1, Ok::<_, ()>);
+            ().ret_poll_ready(1);
+            ().ret_poll_pending::<u8>();
+            ().ret_future_ready(1);
+            ().ret_future_pending::<u8>();
+            ().ret_noop_waker();
+            ().ret_context(&waker);
+            ().ret_control_flow_break::<_, ()>(1);
+            ().ret_control_flow_continue::<(), _>(1);
+            ().ret_cell(1);
+            ().ret_cell_default::<u8>();
+            ().ret_ref_cell(1);
+            ().ret_ref_cell_default::<u8>();
+            ().ret_from::<u8, u16>(1);
+            ().ret_into::<u8, u16>(1);
+            ().ret_try_from::<u16, u8>(300);
+            ().ret_try_into::<u16, u8>(3);
+            ().ret_parse::<u8>("42");
+            ().ret_true();
+            ().ret_false();
+            ().ret_tuple2(1, 2);
+            ().ret_tuple3(1, 2, 3);
+            ().ret_pair(1, 2);
+            ().ret_less();
+            ().ret_greater();
+            ().ret_equal();
+            ().ret_min_of(1, 2);
+            ().ret_max_of(1, 2);
+            ().ret_clamp_of(5, 1, 3);
+            ().ret_secs(1);
+            ().ret_millis(1);
+            ().ret_micros(1);
+            ().ret_nanos(1);
+            ().ret_duration_zero();
+            ().ret_range(0, 4);
+            ().ret_range_inclusive(0, 4);
+            ().ret_range_from(0);
+            ().ret_range_to(4);
+            ().ret_range_full();
+            ().ret_wrapping(1u8);
+            ().ret_saturating(1u8);
+            ().ret_maybe_uninit::<u64>();
+            ().ret_manually_drop(1);
+            ().ret_pin(&1);
+            ().ret_iter_once(1).sum::<i32>();
+            ().ret_iter_empty::<u8>().count();
+            ().ret_iter_repeat(1).take(3).count();
+            ().ret_iter_repeat_with(|| 1).take(3).count();
+            ().ret_repeat_n(1, 3).count();
+            ().ret_zip([1, 2], [3, 4]).count();
+            ().ret_chain_iters([1], [2]).count();
+            ().ret_enumerate([1, 2]).count();
+            ().ret_peekable([1, 2]).peek().copied();
+            ().ret_collect::<Option<()>, _, _>([Some(()), None]);
+            ().ret_type_name::<u8>();
+            ().ret_char('a');
+            ().ret_char_from_u32(97);
+            ().ret_char_from_digit(7, 10);
+            ().ret_slice_of(&1).len();
+            ().ret_slice_of_mut(&mut slot).len();
+            ().ret_array::<u8, 16>(1);
+            ().ret_array_default::<u8, 16>();
+            ().ret_array_from_fn::<_, 16, _>(|i| i);
+            ().ret_utf8_str(b"abc");
+            ().ret_bytes("abc").len();
+            ().ret_phantom::<u8>();
+            ().ret_non_zero_u8(1);
+            ().ret_non_zero_u16(1);
+            ().ret_non_zero_u32(1);
+            ().ret_non_zero_u64(1);
+            ().ret_non_zero_u128(1);
+            ().ret_non_zero_usize(1);
+            ().ret_non_zero_i8(1);
+            ().ret_non_zero_i16(1);
+            ().ret_non_zero_i32(1);
+            ().ret_non_zero_i64(1);
+            ().ret_non_zero_i128(1);
+            ().ret_non_zero_isize(1);
+            ().ret_atomic_bool(true);
+            ().ret_atomic_u8(1);
+            ().ret_atomic_u16(1);
+            ().ret_atomic_u32(1);
+            ().ret_atomic_u64(1);
+            ().ret_atomic_usize(1);
+            ().ret_atomic_i8(1);
+            ().ret_atomic_i16(1);
+            ().ret_atomic_i32(1);
+            ().ret_atomic_i64(1);
+            ().ret_atomic_isize(1);
+            1.discard_self();
+            1.discard_ret();
+            1.discard_and_ret(2);
+            1.chain_discard(|| ());
+            1.chain_discard_ret(|| 2);
+            1.discard_unless(|n| *n > 0);
+            1.discard_if(|n| *n > 0);
+            1.ok_if(|n| *n > 0, ());
+            1.ok_if_else(|n| *n > 0, || ());
+            1.into_some();
+            1.into_ok::<()>();
+            1.into_err::<()>();
+            1.into_poll_ready();
+            1.into_control_flow_break::<()>();
+            1.into_control_flow_continue::<()>();
+            1.also(|_| ());
+            1.also_with_ref(|_| ());
+            1.also_with_mut(|n| *n += 1);
+            1.also_convert(|n| n + 1);
+            1.zip_with(2);
+            1.zip_with_fn(|n| n + 1).1;
+            1.map_self(|n| n + 1);
+            1.replace_with(|n| n + 1);
+            1.pipe_as_ref(|n| n + 1);
+            slot.pipe_as_mut(|n| *n + 1);
+        }
+
+        let mut buf = Buf(&mut out, 0);
+        no_alloc! {
+            ().ret_write_str(&mut buf, "ab");
+            ().ret_write_char(&mut buf, 'c');
+        }
+        assert_eq!(&out[..3], b"abc");
+
+        #[cfg(feature = "alloc")]
+        no_alloc! {
+            ().ret_cow_borrowed("abc");
+            ().ret_lossy_str(b"abc");
+            ().ret_btree_map::<u8, u8>();
+            ().ret_btree_set::<u8>();
+            ().ret_deque::<u8>();
+            ().ret_string_default();
+            ().ret_some_string();
+            ().ret_ok_string::<()>();
+            ().ret_vec::<u8>();
+            ().ret_some_vec::<u8>();
+            ().ret_ok_vec::<u8, ()>();
+        }
+
+        #[cfg(feature = "std")]
+        no_alloc! {
+            ().ret_mutex(1);
+            ().ret_mutex_default::<u8>();
+            ().ret_rw_lock(1);
+            ().ret_rw_lock_default::<u8>();
+            ().ret_io_cursor([1u8, 2]);
+            ().ret_instant_now();
+            ().ret_system_time_now();
+            ().ret_path("a/b");
+            ().ret_path_buf_default();
+        }
+
+        // Sanity check that the counter sees real allocations.
+        let before = allocs();
+        black_box(std::boxed::Box::new(1));
+        assert!(allocs() > before);
+    }
+
+    struct Buf<'a>(&'a mut [u8; 4], usize);
+
+    impl core::fmt::Write for Buf<'_> {
+        fn write_str(&mut self, s: &str) -> core::fmt::Result {
+            let end = self.1 + s.len();
+            self.0[self.1..end].copy_from_slice(s.as_bytes());
+            self.1 = end;
+            Ok(())
+        }
+    }
+}
+
+/// Checks that helpers taking a value by move drop it exactly when
+/// their docs say they do.
+#[cfg(test)]
+mod drop_assertions {
+    use super::*;
+
+    /// Bumps the shared counter each time it is dropped.
+    struct DropCounter<'a>(&'a Cell<u8>);
+
+    impl Drop for DropCounter<'_> {
+        fn drop(&mut self) {
+            self.0.set(self.0.get() + 1);
+        }
+    }
+
+    #[test]
+    fn test_ret_some_if_drops_rejected_value() {
+        let drops = Cell::new(0);
+
+        let kept = ().ret_some_if(true, DropCounter(&drops));
+        assert!(kept.is_some());
+        assert_eq!(drops.get(), 0);
+
+        let gone = ().ret_some_if(false, DropCounter(&drops));
+        assert!(gone.is_none());
+        assert_eq!(drops.get(), 1);
+
+        drop(kept);
+        assert_eq!(drops.get(), 2);
+    }
+
+    #[test]
+    fn test_ret_none_if_drops_rejected_value() {
+        let drops = Cell::new(0);
+
+        let gone = ().ret_none_if(true, DropCounter(&drops));
+        assert!(gone.is_none());
+        assert_eq!(drops.get(), 1);
+
+        let kept = ().ret_none_if(false, DropCounter(&drops));
+        assert!(kept.is_some());
+        assert_eq!(drops.get(), 1);
+    }
+
+    #[test]
+    fn test_ret_manually_drop_never_drops() {
+        let drops = Cell::new(0);
+
+        {
+            let _guard = ().ret_manually_drop(DropCounter(&drops));
+        }
+        assert_eq!(drops.get(), 0);
+
+        let guard = ().ret_manually_drop(DropCounter(&drops));
+        drop(ManuallyDrop::into_inner(guard));
+        assert_eq!(drops.get(), 1);
+    }
+
+    #[test]
+    fn test_discard_and_ret_drops_self() {
+        let drops = Cell::new(0);
+        let value = DropCounter(&drops).discard_and_ret(42);
+        assert_eq!(drops.get(), 1);
+        assert_eq!(value, 42);
+    }
+
+    #[test]
+    fn test_discard_unless_drops_rejected_self() {
+        let drops = Cell::new(0);
+
+        let kept = DropCounter(&drops).discard_unless(|_| true);
+        assert!(kept.is_some());
+        assert_eq!(drops.get(), 0);
+
+        let gone = DropCounter(&drops).discard_unless(|_| false);
+        assert!(gone.is_none());
+        assert_eq!(drops.get(), 1);
+    }
+
+    #[test]
+    fn test_discard_if_drops_rejected_self() {
+        let drops = Cell::new(0);
+
+        let gone = DropCounter(&drops).discard_if(|_| true);
+        assert!(gone.is_none());
+        assert_eq!(drops.get(), 1);
+
+        let kept = DropCounter(&drops).discard_if(|_| false);
+        assert!(kept.is_some());
+        assert_eq!(drops.get(), 1);
+    }
+
+    #[test]
+    fn test_ok_if_drops_rejected_self() {
+        let drops = Cell::new(0);
+
+        let valid = DropCounter(&drops).ok_if(|_| true, "rejected");
+        assert!(valid.is_ok());
+        assert_eq!(drops.get(), 0);
+
+        let invalid =
+            DropCounter(&drops).ok_if(|_| false, "rejected");
+        assert_eq!(invalid.err(), Some("rejected"));
+        assert_eq!(drops.get(), 1);
+    }
+
+    #[test]
+    fn test_ok_if_else_drops_rejected_self() {
+        let drops = Cell::new(0);
+
+        let invalid =
+            DropCounter(&drops).ok_if_else(|_| false, || ());
+        assert!(invalid.is_err());
+        assert_eq!(drops.get(), 1);
+    }
+}
+
+/// Checks that `tap_dbg` only prints in builds with
+/// `debug_assertions`.
+#[cfg(all(test, feature = "std"))]
+mod tap_assertions {
+    use super::*;
+
+    #[test]
+    fn test_tap_dbg_emits_only_with_debug_assertions() {
+        let mut emitted = None;
+        emit_if_debug(&7, |v| emitted = Some(*v));
+        assert_eq!(emitted.is_some(), cfg!(debug_assertions));
+        if let Some(v) = emitted {
+            assert_eq!(v, 7);
+        }
+    }
+
+    #[test]
+    fn test_tap_dbg_returns_self_unchanged() {
+        assert_eq!(Some(3).tap_dbg(), Some(3));
+    }
+}
+
+/// Checks that `into_unit` and its `RetExt` aliases are
+/// interchangeable.
+#[cfg(test)]
+mod alias_assertions {
+    use super::*;
+
+    #[must_use]
+    fn noisy(x: i32) -> i32 {
+        x
+    }
+
+    #[test]
+    #[deny(unused_must_use)]
+    fn test_into_unit_and_discard_self_share_call_sites() {
+        (0..3).for_each(|n| noisy(n).into_unit());
+        (0..3).for_each(|n| noisy(n).discard_self());
+        (0..3).for_each(|n| noisy(n).discard_ret());
+        let (): () = noisy(1).into_unit();
+        let (): () = noisy(1).discard_self();
+    }
+}
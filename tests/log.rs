@@ -0,0 +1,114 @@
+//! Checks the `log` helpers against a logger that captures records.
+
+#![cfg(feature = "log")]
+
+use log::{Level, LevelFilter, Log, Metadata, Record};
+use std::cell::RefCell;
+use unit_ext::*;
+
+std::thread_local! {
+    // Per thread, so tests running in parallel only see their own
+    // records.
+    static RECORDS: RefCell<Vec<(Level, String)>> =
+        const { RefCell::new(Vec::new()) };
+}
+
+struct Capture;
+
+impl Log for Capture {
+    fn enabled(&self, _: &Metadata<'_>) -> bool {
+        true
+    }
+
+    fn log(&self, record: &Record<'_>) {
+        let line = record.args().to_string();
+        RECORDS.with(|r| r.borrow_mut().push((record.level(), line)));
+    }
+
+    fn flush(&self) {}
+}
+
+static LOGGER: Capture = Capture;
+
+/// Runs `f` with the capturing logger installed and returns its
+/// result along with the records it produced.
+fn capture<T>(f: impl FnOnce() -> T) -> (T, Vec<(Level, String)>) {
+    let _ = log::set_logger(&LOGGER);
+    log::set_max_level(LevelFilter::Trace);
+    RECORDS.with(|r| r.borrow_mut().clear());
+    let value = f();
+    (value, RECORDS.with(RefCell::take))
+}
+
+#[test]
+fn test_log_trace() {
+    let (n, records) = capture(|| 7.log_trace("step"));
+    assert_eq!(n, 7);
+    assert_eq!(records, [(Level::Trace, "step: 7".to_owned())]);
+}
+
+#[test]
+fn test_log_debug() {
+    let (rows, records) = capture(|| vec![1, 2].log_debug("rows"));
+    assert_eq!(rows, [1, 2]);
+    assert_eq!(records, [(Level::Debug, "rows: [1, 2]".to_owned())]);
+}
+
+#[test]
+fn test_log_info() {
+    let (name, records) = capture(|| "ferris".log_info("user"));
+    assert_eq!(name, "ferris");
+    assert_eq!(
+        records,
+        [(Level::Info, r#"user: "ferris""#.to_owned())]
+    );
+}
+
+#[test]
+fn test_log_warn() {
+    let (retries, records) = capture(|| Some(3).log_warn("retrying"));
+    assert_eq!(retries, Some(3));
+    assert_eq!(
+        records,
+        [(Level::Warn, "retrying: Some(3)".to_owned())]
+    );
+}
+
+#[test]
+fn test_log_error() {
+    let (res, records) =
+        capture(|| Err::<u8, _>("timeout").log_error("query failed"));
+    assert_eq!(res, Err("timeout"));
+    assert_eq!(
+        records,
+        [(
+            Level::Error,
+            r#"query failed: Err("timeout")"#.to_owned()
+        )]
+    );
+}
+
+#[test]
+fn test_ret_ok_and_log() {
+    let (saved, records) = capture(|| {
+        ().ret_ok_and_log::<_, String>(7, Level::Info, "row saved")
+    });
+    assert_eq!(saved, Ok(7));
+    assert_eq!(records, [(Level::Info, "row saved".to_owned())]);
+}
+
+#[test]
+fn test_ret_err_and_log() {
+    let (failed, records) = capture(|| {
+        ().ret_err_and_log::<u32, _>(
+            "timeout",
+            Level::Error,
+            "query failed",
+        )
+    });
+    assert_eq!(failed, Err("timeout"));
+    assert_eq!(
+        records,
+        [(Level::Error, r#"query failed: "timeout""#.to_owned())]
+    );
+}